@@ -1,11 +1,18 @@
-use br_primitives::cli::{Configuration, Result as CliResult};
+use br_primitives::{
+	cli::{Configuration, Result as CliResult},
+	constants::cli::{DEFAULT_SENTRY_MAX_BREADCRUMBS, DEFAULT_SHUTDOWN_DRAIN_TIMEOUT_SEC},
+	sub_display_format,
+};
 
 use sc_service::{Error as ServiceError, TaskManager};
 use sc_utils::metrics::{TOKIO_THREADS_ALIVE, TOKIO_THREADS_TOTAL};
 
 use futures::{future, future::FutureExt, pin_mut, select, Future};
 use sentry::ClientInitGuard;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+
+const LOG_TARGET: &str = "bifrost-relayer";
+const SUB_LOG_TARGET: &str = "runner";
 
 #[cfg(target_family = "unix")]
 async fn main<F, E>(func: F) -> Result<(), E>
@@ -47,6 +54,35 @@ pub fn build_runtime() -> Result<tokio::runtime::Runtime, std::io::Error> {
 		.build()
 }
 
+/// Shuts down the tokio runtime, waiting at most `drain_timeout` for the in-flight work to drain.
+/// Work still pending after the timeout is abandoned, and the pending submissions of each chain
+/// are logged.
+fn shutdown_runtime(
+	tokio_runtime: tokio::runtime::Runtime,
+	drain_timeout: Duration,
+	chain_names: &[String],
+) {
+	let pending: Vec<String> = chain_names
+		.iter()
+		.map(|chain_name| (chain_name, br_metrics::backlog_depth(chain_name, "submission")))
+		.filter(|(_, depth)| *depth > 0)
+		.map(|(chain_name, depth)| format!("{}({})", chain_name, depth))
+		.collect();
+
+	let started_at = Instant::now();
+	tokio_runtime.shutdown_timeout(drain_timeout);
+
+	if started_at.elapsed() >= drain_timeout {
+		log::warn!(
+			target: LOG_TARGET,
+			"-[{}] ⚠️  Shutdown drain timed out after {:?}. Abandoned the pending work. (submissions: {})",
+			sub_display_format(SUB_LOG_TARGET),
+			drain_timeout,
+			if pending.is_empty() { String::from("-") } else { pending.join(", ") },
+		);
+	}
+}
+
 /// A Bifrost-Relayer CLI runtime that can be used to run a relayer
 pub struct Runner {
 	config: Configuration,
@@ -64,6 +100,7 @@ impl Runner {
 				sentry_config.is_enabled,
 				sentry_config.dsn,
 				sentry_config.environment,
+				sentry_config.max_breadcrumbs.unwrap_or(DEFAULT_SENTRY_MAX_BREADCRUMBS),
 			),
 		})
 	}
@@ -76,17 +113,53 @@ impl Runner {
 		F: Future<Output = Result<TaskManager, E>>,
 		E: std::error::Error + Send + Sync + 'static + From<ServiceError>,
 	{
+		let drain_timeout = Duration::from_secs(
+			self.config
+				.relayer_config
+				.system
+				.shutdown_drain_timeout
+				.unwrap_or(DEFAULT_SHUTDOWN_DRAIN_TIMEOUT_SEC),
+		);
+		let chain_names: Vec<String> = self
+			.config
+			.relayer_config
+			.evm_providers
+			.iter()
+			.map(|evm_provider| evm_provider.name.clone())
+			.collect();
+
 		let mut task_manager = self.tokio_runtime.block_on(initialize(self.config))?;
 		let res = self.tokio_runtime.block_on(main(task_manager.future().fuse()));
 		// We need to drop the task manager here to inform all tasks that they should shut down.
 		//
 		// This is important to be done before we instruct the tokio runtime to shutdown. Otherwise
-		// the tokio runtime will wait the full 60 seconds for all tasks to stop.
+		// the tokio runtime will wait the full drain timeout for all tasks to stop.
 		drop(task_manager);
 
-		// Give all futures 60 seconds to shutdown, before tokio "leaks" them.
-		self.tokio_runtime.shutdown_timeout(Duration::from_secs(60));
+		// Give all futures the drain timeout to shutdown, before tokio "leaks" them.
+		shutdown_runtime(self.tokio_runtime, drain_timeout, &chain_names);
 
 		res.map_err(Into::into)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn shutdown_is_bounded_by_drain_timeout() {
+		let tokio_runtime = build_runtime().unwrap();
+		br_metrics::increase_backlog_depth("shutdown-test", "submission");
+		// a submission that never completes
+		tokio_runtime.spawn_blocking(|| std::thread::sleep(Duration::from_secs(60)));
+
+		let started_at = Instant::now();
+		shutdown_runtime(
+			tokio_runtime,
+			Duration::from_millis(100),
+			&[String::from("shutdown-test")],
+		);
+		assert!(started_at.elapsed() < Duration::from_secs(10));
+	}
+}
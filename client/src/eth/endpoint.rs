@@ -0,0 +1,325 @@
+use std::{
+	fmt::{Debug, Formatter},
+	sync::{Arc, Mutex},
+	time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use ethers::{
+	providers::{JsonRpcClient, Middleware, PendingTransaction, Provider, ProviderError, RpcError},
+	types::{transaction::eip2718::TypedTransaction, BlockId, Bytes, NameOrAddress, U256},
+};
+
+use super::EthClient;
+
+/// The weight given to the latest latency sample when updating the moving average.
+const LATENCY_EWMA_WEIGHT: f64 = 0.3;
+
+/// The thresholds used to decide whether an RPC endpoint has been degraded.
+#[derive(Clone, Copy, Debug)]
+pub struct HealthThresholds {
+	/// An endpoint is degraded when its average latency exceeds this duration.
+	pub latency: Duration,
+	/// An endpoint is degraded when its consecutive errors reaches this count.
+	pub consecutive_errors: u32,
+	/// A degraded endpoint is retried after this duration has passed since its degradation.
+	pub recovery_interval: Duration,
+}
+
+#[derive(Clone, Debug, Default)]
+/// The health status of a single RPC endpoint.
+struct EndpointHealth {
+	/// The exponentially weighted moving average latency in milliseconds.
+	latency_ms: Option<f64>,
+	/// The number of consecutive failed requests.
+	consecutive_errors: u32,
+	/// The moment the endpoint has been marked as degraded.
+	degraded_at: Option<Instant>,
+}
+
+/// Tracks the health of each RPC endpoint and selects the endpoint to use. The primary endpoint
+/// (index 0) is always preferred, fallbacks are only used while the preceding endpoints are
+/// degraded.
+#[derive(Debug)]
+pub struct EndpointSelector {
+	/// The health status of each endpoint.
+	health: Mutex<Vec<EndpointHealth>>,
+	/// The thresholds used to detect degradation.
+	thresholds: HealthThresholds,
+}
+
+impl EndpointSelector {
+	/// Instantiates a new `EndpointSelector` for the given number of endpoints.
+	pub fn new(endpoints: usize, thresholds: HealthThresholds) -> Self {
+		Self { health: Mutex::new(vec![EndpointHealth::default(); endpoints]), thresholds }
+	}
+
+	/// Returns the index of the endpoint to use. A degraded endpoint is selected again once its
+	/// recovery interval has passed, so that the primary will be used again when it recovers. If
+	/// every endpoint is degraded, the primary will be used.
+	pub fn select(&self, now: Instant) -> usize {
+		let health = self.health.lock().unwrap();
+		health
+			.iter()
+			.position(|endpoint| match endpoint.degraded_at {
+				Some(degraded_at) => {
+					now.saturating_duration_since(degraded_at) >= self.thresholds.recovery_interval
+				},
+				None => true,
+			})
+			.unwrap_or_default()
+	}
+
+	/// Reports a successful request and its latency to the given endpoint.
+	pub fn report_success(&self, index: usize, latency: Duration, now: Instant) {
+		let mut health = self.health.lock().unwrap();
+		let endpoint = &mut health[index];
+		let sample = latency.as_secs_f64() * 1000.0;
+
+		endpoint.consecutive_errors = 0;
+		endpoint.latency_ms = match (endpoint.latency_ms, endpoint.degraded_at) {
+			// a recovering endpoint starts over with a fresh average
+			(Some(average), None) => {
+				Some(average * (1.0 - LATENCY_EWMA_WEIGHT) + sample * LATENCY_EWMA_WEIGHT)
+			},
+			_ => Some(sample),
+		};
+		endpoint.degraded_at = if endpoint.latency_ms.unwrap_or_default()
+			> self.thresholds.latency.as_secs_f64() * 1000.0
+		{
+			Some(now)
+		} else {
+			None
+		};
+	}
+
+	/// Reports a failed request to the given endpoint.
+	pub fn report_failure(&self, index: usize, now: Instant) {
+		let mut health = self.health.lock().unwrap();
+		let endpoint = &mut health[index];
+
+		endpoint.consecutive_errors = endpoint.consecutive_errors.saturating_add(1);
+		if endpoint.consecutive_errors >= self.thresholds.consecutive_errors {
+			endpoint.degraded_at = Some(now);
+		}
+	}
+
+	/// Returns `true` if any endpoint isn't degraded.
+	pub fn is_any_healthy(&self) -> bool {
+		self.health
+			.lock()
+			.unwrap()
+			.iter()
+			.any(|endpoint| endpoint.degraded_at.is_none())
+	}
+
+	/// Returns the average latency(ms), consecutive errors and the degraded status of the given
+	/// endpoint.
+	pub fn status(&self, index: usize) -> (f64, u32, bool) {
+		let health = self.health.lock().unwrap();
+		let endpoint = &health[index];
+		(
+			endpoint.latency_ms.unwrap_or_default(),
+			endpoint.consecutive_errors,
+			endpoint.degraded_at.is_some(),
+		)
+	}
+}
+
+/// The RPC endpoints of a single chain. The first endpoint is the primary, followed by fallbacks.
+pub struct RpcEndpoints<T> {
+	/// The ethers.rs wrappers for each endpoint.
+	providers: Vec<Arc<Provider<T>>>,
+	/// The health tracker of the endpoints.
+	pub selector: EndpointSelector,
+}
+
+impl<T> RpcEndpoints<T> {
+	/// Instantiates a new `RpcEndpoints` instance.
+	pub fn new(
+		primary: Arc<Provider<T>>,
+		fallbacks: Vec<Arc<Provider<T>>>,
+		thresholds: HealthThresholds,
+	) -> Self {
+		let providers: Vec<Arc<Provider<T>>> = std::iter::once(primary).chain(fallbacks).collect();
+		Self { selector: EndpointSelector::new(providers.len(), thresholds), providers }
+	}
+
+	/// Returns the primary provider.
+	pub fn primary(&self) -> Arc<Provider<T>> {
+		self.providers[0].clone()
+	}
+
+	/// Returns the index and provider of the endpoint to use.
+	pub fn select(&self) -> (usize, &Provider<T>) {
+		let index = self.selector.select(Instant::now());
+		(index, &self.providers[index])
+	}
+
+	/// Returns `true` if any fallback endpoint has been configured.
+	pub fn has_fallbacks(&self) -> bool {
+		self.providers.len() > 1
+	}
+}
+
+/// Returns `true` if the given error implies a failure of the endpoint itself, such as transport
+/// errors and timeouts. Errors responded by the node (e.g. reverts) aren't failures of the
+/// endpoint.
+pub fn is_endpoint_failure(error: &ProviderError) -> bool {
+	match error {
+		ProviderError::JsonRpcClientError(error) => !error.is_error_response(),
+		ProviderError::HTTPError(_) => true,
+		_ => false,
+	}
+}
+
+/// The middleware that sends the transaction submission requests (gas estimation, nonce and raw
+/// transactions) to the healthiest RPC endpoint of the client. Every other request is sent to the
+/// primary endpoint.
+pub struct FailoverMiddleware<T> {
+	/// The client whose endpoints are used.
+	client: Arc<EthClient<T>>,
+}
+
+impl<T> FailoverMiddleware<T> {
+	/// Instantiates a new `FailoverMiddleware` instance.
+	pub fn new(client: Arc<EthClient<T>>) -> Self {
+		Self { client }
+	}
+}
+
+impl<T> Debug for FailoverMiddleware<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("FailoverMiddleware")
+			.field("chain", &self.client.metadata.name)
+			.finish()
+	}
+}
+
+#[async_trait]
+impl<T: JsonRpcClient> Middleware for FailoverMiddleware<T> {
+	type Error = ProviderError;
+	type Provider = T;
+	type Inner = Provider<T>;
+
+	fn inner(&self) -> &Provider<T> {
+		&self.client.provider
+	}
+
+	async fn estimate_gas(
+		&self,
+		tx: &TypedTransaction,
+		block: Option<BlockId>,
+	) -> Result<U256, ProviderError> {
+		let (index, provider) = self.client.endpoints.select();
+		let started_at = Instant::now();
+		let result = provider.estimate_gas(tx, block).await;
+		self.client.report_endpoint_result(index, started_at, &result);
+		result
+	}
+
+	async fn get_transaction_count<A: Into<NameOrAddress> + Send + Sync>(
+		&self,
+		from: A,
+		block: Option<BlockId>,
+	) -> Result<U256, ProviderError> {
+		let (index, provider) = self.client.endpoints.select();
+		let started_at = Instant::now();
+		let result = provider.get_transaction_count(from, block).await;
+		self.client.report_endpoint_result(index, started_at, &result);
+		result
+	}
+
+	async fn send_raw_transaction<'a>(
+		&'a self,
+		tx: Bytes,
+	) -> Result<PendingTransaction<'a, T>, ProviderError> {
+		let (index, provider) = self.client.endpoints.select();
+		let started_at = Instant::now();
+		let result = provider.send_raw_transaction(tx).await;
+		self.client.report_endpoint_result(index, started_at, &result);
+		result
+	}
+}
+
+/// Returns the label of the given endpoint used on logs and metrics. URLs are never exposed since
+/// they may contain API keys.
+pub fn endpoint_label(index: usize) -> String {
+	match index {
+		0 => String::from("primary"),
+		n => format!("fallback-{}", n),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn thresholds() -> HealthThresholds {
+		HealthThresholds {
+			latency: Duration::from_millis(500),
+			consecutive_errors: 2,
+			recovery_interval: Duration::from_secs(30),
+		}
+	}
+
+	#[test]
+	fn fails_over_on_consecutive_errors_and_returns_to_primary() {
+		let selector = EndpointSelector::new(2, thresholds());
+		let now = Instant::now();
+
+		selector.report_failure(0, now);
+		assert_eq!(selector.select(now), 0);
+		selector.report_failure(0, now);
+		assert_eq!(selector.select(now), 1);
+
+		// the primary is retried after the recovery interval and kept if it succeeds
+		let later = now + Duration::from_secs(30);
+		assert_eq!(selector.select(later), 0);
+		selector.report_success(0, Duration::from_millis(100), later);
+		assert_eq!(selector.select(later), 0);
+		assert_eq!(selector.status(0), (100.0, 0, false));
+	}
+
+	#[test]
+	fn fails_over_on_high_latency() {
+		let selector = EndpointSelector::new(2, thresholds());
+		let now = Instant::now();
+
+		selector.report_success(0, Duration::from_millis(100), now);
+		assert_eq!(selector.select(now), 0);
+		selector.report_success(0, Duration::from_secs(3), now);
+		assert_eq!(selector.select(now), 1);
+
+		// a recovering endpoint which is still slow will be degraded again
+		let later = now + Duration::from_secs(30);
+		selector.report_success(0, Duration::from_secs(1), later);
+		assert_eq!(selector.select(later), 1);
+	}
+
+	#[test]
+	fn only_transport_errors_are_endpoint_failures() {
+		use ethers::providers::{JsonRpcError, MockError};
+
+		let reverted = MockError::JsonRpcError(JsonRpcError {
+			code: 3,
+			message: String::from("execution reverted"),
+			data: None,
+		});
+		assert!(!is_endpoint_failure(&ProviderError::from(reverted)));
+		assert!(is_endpoint_failure(&ProviderError::from(MockError::EmptyResponses)));
+		assert!(!is_endpoint_failure(&ProviderError::CustomError(String::from("ens"))));
+	}
+
+	#[test]
+	fn uses_primary_when_every_endpoint_is_degraded() {
+		let selector = EndpointSelector::new(2, thresholds());
+		let now = Instant::now();
+
+		(0..2).for_each(|index| {
+			selector.report_failure(index, now);
+			selector.report_failure(index, now);
+		});
+		assert_eq!(selector.select(now), 0);
+	}
+}
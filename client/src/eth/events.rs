@@ -1,17 +1,25 @@
-use std::sync::Arc;
+use std::{
+	collections::{BTreeMap, BTreeSet},
+	sync::Arc,
+	time::Instant,
+};
 
 use ethers::{
 	providers::JsonRpcClient,
-	types::{BlockNumber, Filter, Log, SyncingStatus, U64},
+	types::{BlockId, BlockNumber, Filter, Log, SyncingStatus, H256, U64},
 };
 use tokio::{
 	sync::broadcast::{self, Receiver, Sender},
 	time::{sleep, Duration},
 };
+use tokio_stream::{wrappers::BroadcastStream, StreamExt, StreamMap};
 
 use br_primitives::{
-	bootstrap::BootstrapSharedData,
+	abbreviate_address,
+	bootstrap::{transition_chain_state, BootstrapSharedData, LogThrottle},
+	constants::config::REORG_TRACKING_CAPACITY,
 	eth::{BootstrapState, ChainID},
+	reorg::{find_reorg, BlockHashRing, Reorg},
 	sub_display_format,
 };
 
@@ -46,6 +54,126 @@ impl EventReceiver {
 	}
 }
 
+/// The merged stream of multiple block channels. Each yielded message is paired with the chain ID
+/// of the channel it was received from.
+pub type EventStream = StreamMap<ChainID, BroadcastStream<EventMessage>>;
+
+/// Merges the given receivers into a single `EventStream`. The stream yields whichever channel has
+/// a message ready, while messages of the same channel are always yielded in the order they were
+/// sent. Receivers must have unique chain IDs, a duplicated ID replaces the previous receiver.
+pub fn merge_event_receivers(receivers: Vec<EventReceiver>) -> EventStream {
+	let mut stream = StreamMap::new();
+	receivers.into_iter().for_each(|receiver| {
+		stream.insert(receiver.id, BroadcastStream::new(receiver.receiver));
+	});
+	stream
+}
+
+/// The read position of an `EventStream`. Tracks the latest block received from each block
+/// channel, so that the blocks dropped by a lagged receiver can be re-fetched.
+pub struct EventStreamCursor {
+	/// The throttle of the logs emitted on malformed event stream items.
+	log_throttle: LogThrottle,
+	/// The latest block with received logs of each channel.
+	latest_blocks: BTreeMap<ChainID, U64>,
+	/// The channels that have dropped messages since their latest received message.
+	lagged: BTreeSet<ChainID>,
+}
+
+impl EventStreamCursor {
+	/// Instantiates a new `EventStreamCursor` that logs malformed items once per the given
+	/// interval.
+	pub fn new(log_interval: Duration) -> Self {
+		Self {
+			log_throttle: LogThrottle::new(log_interval),
+			latest_blocks: BTreeMap::new(),
+			lagged: BTreeSet::new(),
+		}
+	}
+
+	/// Advances the cursor of the given channel to the received message. Returns the first
+	/// dropped block if the channel has lagged behind since its latest received message.
+	fn advance(&mut self, id: ChainID, msg: &EventMessage) -> Option<U64> {
+		let dropped_from = if self.lagged.remove(&id) {
+			self.latest_blocks.get(&id).map(|block| block.saturating_add(U64::from(1u64)))
+		} else {
+			None
+		};
+
+		let latest_block = msg
+			.event_logs
+			.iter()
+			.filter_map(|log| log.block_number)
+			.max()
+			.unwrap_or(msg.block_number);
+		self.latest_blocks.insert(id, latest_block);
+		dropped_from
+	}
+}
+
+/// Receives the next message of the given stream. Erroneous items (ex. messages dropped by a
+/// lagged receiver) are counted and skipped instead of aborting the stream, and are only logged
+/// once per throttle interval. If messages have been dropped, the first dropped block is returned
+/// with the next message, so that the dropped range can be re-fetched.
+pub async fn next_event_message(
+	stream: &mut EventStream,
+	cursor: &mut EventStreamCursor,
+	chain_name: &str,
+) -> (ChainID, EventMessage, Option<U64>) {
+	loop {
+		match stream.next().await.unwrap() {
+			(id, Ok(msg)) => {
+				let dropped_from = cursor.advance(id, &msg);
+				return (id, msg, dropped_from);
+			},
+			(id, Err(error)) => {
+				br_metrics::increase_malformed_stream_items(chain_name);
+				cursor.lagged.insert(id);
+				if cursor.log_throttle.should_log(Instant::now()) {
+					log::warn!(
+						target: chain_name,
+						"-[{}] ⚠️  Skipped a malformed event stream item of chain({}): {}",
+						sub_display_format(SUB_LOG_TARGET),
+						id,
+						error,
+					);
+				}
+			},
+		}
+	}
+}
+
+/// Re-fetches the logs of the blocks dropped before the given message, and prepends them to the
+/// message. The message then covers the dropped range.
+pub async fn refetch_dropped_logs<T: JsonRpcClient>(
+	client: &EthClient<T>,
+	dropped_from: U64,
+	msg: &mut EventMessage,
+) {
+	if dropped_from >= msg.block_number {
+		return;
+	}
+	let to = msg.block_number.saturating_sub(U64::from(1u64));
+	let filter = Filter::new()
+		.from_block(BlockNumber::from(dropped_from))
+		.to_block(BlockNumber::from(to))
+		.address(client.protocol_contracts.socket.address());
+
+	let mut dropped_logs = client.get_logs(&filter).await;
+	log::warn!(
+		target: &client.get_chain_name(),
+		"-[{}] 🔁 Re-fetched #({:?} … {:?}) dropped by the event stream with target logs({:?})",
+		sub_display_format(SUB_LOG_TARGET),
+		dropped_from,
+		to,
+		dropped_logs.len(),
+	);
+
+	dropped_logs.append(&mut msg.event_logs);
+	msg.event_logs = dropped_logs;
+	msg.block_number = dropped_from;
+}
+
 const SUB_LOG_TARGET: &str = "event-manager";
 
 /// The essential task that listens and handle new events.
@@ -56,6 +184,8 @@ pub struct EventManager<T> {
 	pub sender: Sender<EventMessage>,
 	/// The block waiting for enough confirmations.
 	waiting_block: U64,
+	/// The hashes of the latest processed blocks, tracked to detect reorgs.
+	block_hashes: BlockHashRing,
 	/// The bootstrap shared data.
 	bootstrap_shared_data: Arc<BootstrapSharedData>,
 	/// The flag whether the relayer has enabled self balance synchronization. This field will be
@@ -76,6 +206,7 @@ impl<T: JsonRpcClient> EventManager<T> {
 			client,
 			sender,
 			waiting_block: U64::default(),
+			block_hashes: BlockHashRing::new(REORG_TRACKING_CAPACITY),
 			bootstrap_shared_data,
 			is_balance_sync_enabled,
 		}
@@ -85,6 +216,7 @@ impl<T: JsonRpcClient> EventManager<T> {
 	async fn initialize(&mut self) {
 		self.client.verify_chain_id().await;
 		self.client.verify_minimum_balance().await;
+		self.client.verify_relayer_registration().await;
 
 		// initialize waiting block to the latest block
 		self.waiting_block = self.client.get_latest_block_number().await;
@@ -106,15 +238,23 @@ impl<T: JsonRpcClient> EventManager<T> {
 		self.initialize().await;
 
 		loop {
+			self.bootstrap_shared_data
+				.health_state
+				.report_progress(&self.client.get_chain_name(), Instant::now());
+
 			if self.is_bootstrap_state_synced_as(BootstrapState::NormalStart).await {
 				let latest_block = self.client.get_latest_block_number().await;
 				while self.is_block_confirmed(latest_block) {
 					self.process_confirmed_block().await;
+					self.bootstrap_shared_data
+						.health_state
+						.report_progress(&self.client.get_chain_name(), Instant::now());
 
 					if self.is_balance_sync_enabled {
 						self.client.sync_balance().await;
 					}
 				}
+				self.report_backlog_depth(latest_block);
 			}
 
 			sleep(Duration::from_millis(self.client.metadata.call_interval)).await;
@@ -124,6 +264,10 @@ impl<T: JsonRpcClient> EventManager<T> {
 	/// Process the confirmed block and verifies if any events emitted from the target
 	/// contracts.
 	async fn process_confirmed_block(&mut self) {
+		if self.rewind_on_deep_reorg().await {
+			return;
+		}
+
 		let from = self.waiting_block;
 		let to = from.saturating_add(
 			self.client.metadata.get_logs_batch_size.saturating_sub(U64::from(1u64)),
@@ -156,6 +300,111 @@ impl<T: JsonRpcClient> EventManager<T> {
 		}
 
 		self.increment_waiting_block(to);
+
+		if self.client.metadata.deep_reorg_threshold.is_some() {
+			if let Some(hash) = self.get_block_hash(to).await {
+				self.block_hashes.record(to.as_u64(), hash);
+			}
+		}
+	}
+
+	/// Returns the canonical hash of the given block.
+	async fn get_block_hash(&self, block_number: U64) -> Option<H256> {
+		self.client
+			.get_block(BlockId::Number(BlockNumber::Number(block_number)))
+			.await
+			.and_then(|block| block.hash)
+	}
+
+	/// Detects whether the processed blocks have been reorged.
+	async fn detect_reorg(&self) -> Option<Reorg> {
+		let (latest_number, latest_hash) = self.block_hashes.latest()?;
+		if self.get_block_hash(U64::from(latest_number)).await? == latest_hash {
+			return None;
+		}
+
+		let mut canonical_hashes = BTreeMap::new();
+		for (number, hash) in self.block_hashes.iter_newest() {
+			let canonical_hash = match self.get_block_hash(U64::from(*number)).await {
+				Some(canonical_hash) => canonical_hash,
+				None => break,
+			};
+			canonical_hashes.insert(*number, canonical_hash);
+			if canonical_hash == *hash {
+				break;
+			}
+		}
+		find_reorg(&self.block_hashes, &canonical_hashes)
+	}
+
+	/// Rewinds the waiting block to the first reorged block if the processed blocks have been
+	/// reorged deeper than the threshold, so that the affected range is re-processed. The socket
+	/// handler discards the re-processed messages it has already relayed (keyed by request chain,
+	/// sequence and status), within its tracking capacity. Returns `true` if rewound.
+	async fn rewind_on_deep_reorg(&mut self) -> bool {
+		let threshold = match self.client.metadata.deep_reorg_threshold {
+			Some(threshold) => threshold,
+			None => return false,
+		};
+		let reorg = match self.detect_reorg().await {
+			Some(reorg) => reorg,
+			None => return false,
+		};
+		self.block_hashes.truncate_from(reorg.from_block);
+
+		if reorg.depth() < threshold.as_u64() {
+			log::warn!(
+				target: &self.client.get_chain_name(),
+				"-[{}] ⚠️  Detected a reorg of #{}..#{} ({} blocks) below the threshold({})",
+				sub_display_format(SUB_LOG_TARGET),
+				reorg.from_block,
+				reorg.to_block,
+				reorg.depth(),
+				threshold,
+			);
+			return false;
+		}
+
+		let msg = format!(
+			"Detected a deep reorg of #{}..#{} ({} blocks). Re-processing from #{} to #{:?}",
+			reorg.from_block,
+			reorg.to_block,
+			reorg.depth(),
+			reorg.from_block,
+			self.waiting_block.saturating_sub(U64::from(1u64)),
+		);
+		log::warn!(
+			target: &self.client.get_chain_name(),
+			"-[{}] 🔀 {}",
+			sub_display_format(SUB_LOG_TARGET),
+			msg,
+		);
+		sentry::capture_message(
+			format!(
+				"[{}]-[{}]-[{}] 🔀 {}",
+				&self.client.get_chain_name(),
+				SUB_LOG_TARGET,
+				abbreviate_address(&self.client.address()),
+				msg,
+			)
+			.as_str(),
+			sentry::Level::Warning,
+		);
+
+		self.waiting_block = U64::from(reorg.from_block);
+		true
+	}
+
+	/// Reports the number of block messages queued for the handlers, and the number of blocks
+	/// awaiting enough confirmations.
+	fn report_backlog_depth(&self, latest_block: U64) {
+		let chain_name = self.client.get_chain_name();
+		br_metrics::set_backlog_depth(&chain_name, "channel", self.sender.len() as u64);
+		br_metrics::set_backlog_depth(
+			&chain_name,
+			"confirmation",
+			latest_block.saturating_sub(self.waiting_block).as_u64(),
+		);
 	}
 
 	/// Increment the waiting block.
@@ -181,11 +430,13 @@ impl<T: JsonRpcClient> EventManager<T> {
 					status.highest_block,
 				);
 			} else {
-				for state in self.bootstrap_shared_data.bootstrap_states.write().await.iter_mut() {
-					if *state == BootstrapState::NodeSyncing {
-						*state = BootstrapState::BootstrapRoundUpPhase1;
-					}
-				}
+				// only this chain transitions, so that a lagging chain remains visible.
+				transition_chain_state(
+					&mut *self.bootstrap_shared_data.bootstrap_states.write().await,
+					self.client.get_chain_id(),
+					BootstrapState::NodeSyncing,
+					BootstrapState::BootstrapRoundUpPhase1,
+				);
 				return;
 			}
 
@@ -207,7 +458,78 @@ impl<T: JsonRpcClient> BootstrapHandler for EventManager<T> {
 			.bootstrap_states
 			.read()
 			.await
-			.iter()
+			.values()
 			.all(|s| *s == state)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[tokio::test]
+	async fn merge_event_receivers_preserves_order_per_source() {
+		let (sender_a, receiver_a) = broadcast::channel(16);
+		let (sender_b, receiver_b) = broadcast::channel(16);
+
+		for number in 0..5u64 {
+			sender_a.send(EventMessage::new(U64::from(number), vec![])).unwrap();
+			sender_b.send(EventMessage::new(U64::from(100 + number), vec![])).unwrap();
+		}
+		drop(sender_a);
+		drop(sender_b);
+
+		let mut stream = merge_event_receivers(vec![
+			EventReceiver::new(1, receiver_a),
+			EventReceiver::new(2, receiver_b),
+		]);
+
+		let mut received: BTreeMap<ChainID, Vec<U64>> = BTreeMap::new();
+		while let Some((id, msg)) = stream.next().await {
+			received.entry(id).or_default().push(msg.unwrap().block_number);
+		}
+
+		assert_eq!(received[&1], (0..5u64).map(U64::from).collect::<Vec<_>>());
+		assert_eq!(received[&2], (100..105u64).map(U64::from).collect::<Vec<_>>());
+	}
+
+	#[tokio::test]
+	async fn malformed_stream_items_are_skipped() {
+		let (sender, receiver) = broadcast::channel(2);
+		let mut stream = merge_event_receivers(vec![EventReceiver::new(1, receiver)]);
+		let mut cursor = EventStreamCursor::new(Duration::from_secs(30));
+
+		let log_at =
+			|number: u64| Log { block_number: Some(U64::from(number)), ..Default::default() };
+		sender
+			.send(EventMessage::new(U64::from(0), vec![log_at(0), log_at(1)]))
+			.unwrap();
+		let (_, msg, dropped_from) =
+			next_event_message(&mut stream, &mut cursor, "malformed-stream-test").await;
+		assert_eq!(msg.block_number, U64::from(0));
+		assert_eq!(dropped_from, None);
+
+		for number in 2..6u64 {
+			sender.send(EventMessage::new(U64::from(number), vec![log_at(number)])).unwrap();
+		}
+
+		// the receiver has lagged behind, so the stream first yields an error item.
+		let (id, msg, dropped_from) =
+			next_event_message(&mut stream, &mut cursor, "malformed-stream-test").await;
+		assert_eq!(id, 1);
+		assert_eq!(msg.block_number, U64::from(4));
+		assert_eq!(
+			br_metrics::MALFORMED_STREAM_ITEMS
+				.with_label_values(&["malformed-stream-test"])
+				.get(),
+			1
+		);
+		// the blocks after the latest received log have been dropped
+		assert_eq!(dropped_from, Some(U64::from(2)));
+
+		let (_, msg, dropped_from) =
+			next_event_message(&mut stream, &mut cursor, "malformed-stream-test").await;
+		assert_eq!(msg.block_number, U64::from(5));
+		assert_eq!(dropped_from, None);
+	}
+}
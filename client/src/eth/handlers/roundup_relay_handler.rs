@@ -1,20 +1,27 @@
-use std::{collections::BTreeMap, str::FromStr, sync::Arc, time::Duration};
+use std::{
+	collections::BTreeMap,
+	str::FromStr,
+	sync::Arc,
+	time::{Duration, Instant},
+};
 
 use async_trait::async_trait;
 use ethers::{
 	abi::{encode, Detokenize, Token, Tokenize},
 	contract::EthLogDecode,
 	providers::{JsonRpcClient, Provider},
-	types::{Address, Bytes, Filter, Log, Signature, TransactionRequest, H256, U256},
+	types::{Address, Bytes, Filter, Log, Signature, TransactionRequest, H256, U256, U64},
 };
-use tokio::{sync::broadcast::Receiver, time::sleep};
+use tokio::time::sleep;
 use tokio_stream::StreamExt;
 
 use br_primitives::{
-	bootstrap::BootstrapSharedData,
+	abbreviate_address,
+	bootstrap::{BootstrapSharedData, TransitionalStateWaiter},
+	cli::HandlerConfig,
 	constants::{
 		cli::DEFAULT_BOOTSTRAP_ROUND_OFFSET,
-		config::BOOTSTRAP_BLOCK_CHUNK_SIZE,
+		config::{BOOTSTRAP_BLOCK_CHUNK_SIZE, MALFORMED_STREAM_ITEM_LOG_INTERVAL_MS},
 		errors::{INVALID_BIFROST_NATIVENESS, INVALID_CONTRACT_ABI},
 	},
 	contracts::{
@@ -23,13 +30,19 @@ use br_primitives::{
 			RoundUpSubmit, SerializedRoundUp, Signatures, SocketContract, SocketContractEvents,
 		},
 	},
-	eth::{BootstrapState, ChainID, GasCoefficient, RecoveredSignature, RoundUpEventStatus},
+	eth::{
+		BootstrapState, ChainID, DecodeErrorSeverity, ErrorSeverity, GasCoefficient,
+		RecoveredSignature, RelayerSetKind, RoundUpEventStatus,
+	},
 	sub_display_format,
 	tx::{TxRequest, TxRequestMessage, TxRequestMetadata, TxRequestSender, VSPPhase2Metadata},
 };
 
 use crate::eth::{
-	events::EventMessage,
+	events::{
+		merge_event_receivers, next_event_message, refetch_dropped_logs, EventReceiver,
+		EventStream, EventStreamCursor,
+	},
 	traits::{BootstrapHandler, Handler},
 	EthClient,
 };
@@ -42,14 +55,22 @@ pub struct RoundupRelayHandler<T> {
 	pub client: Arc<EthClient<T>>,
 	/// The senders that sends messages to each tx request channel.
 	tx_request_senders: BTreeMap<ChainID, Arc<TxRequestSender>>,
-	/// The receiver that consumes new events from the block channel.
-	event_receiver: Receiver<EventMessage>,
+	/// The stream that consumes new events from the block channels.
+	event_stream: EventStream,
+	/// The read position of the event stream.
+	event_stream_cursor: EventStreamCursor,
 	/// `EthClient`s to interact with provided networks except bifrost network.
 	external_clients: Vec<Arc<EthClient<T>>>,
 	/// Signature of RoundUp Event.
 	roundup_signature: H256,
+	/// The relayer set used to verify whether this relayer was selected at the event's round.
+	relayer_set: RelayerSetKind,
+	/// The severities of RoundUp event decode errors.
+	decode_error_severity: DecodeErrorSeverity,
 	/// The bootstrap shared data.
 	bootstrap_shared_data: Arc<BootstrapSharedData>,
+	/// The waiter used while the bootstrap states are transitional.
+	transitional_state_waiter: TransitionalStateWaiter,
 }
 
 #[async_trait]
@@ -61,7 +82,15 @@ impl<T: JsonRpcClient> Handler for RoundupRelayHandler<T> {
 
 				sleep(Duration::from_millis(self.client.metadata.call_interval)).await;
 			} else if self.is_bootstrap_state_synced_as(BootstrapState::NormalStart).await {
-				let msg = self.event_receiver.recv().await.unwrap();
+				let (_, mut msg, dropped_from) = next_event_message(
+					&mut self.event_stream,
+					&mut self.event_stream_cursor,
+					&self.client.get_chain_name(),
+				)
+				.await;
+				if let Some(dropped_from) = dropped_from {
+					refetch_dropped_logs(&self.client, dropped_from, &mut msg).await;
+				}
 
 				log::info!(
 					target: &self.client.get_chain_name(),
@@ -74,9 +103,16 @@ impl<T: JsonRpcClient> Handler for RoundupRelayHandler<T> {
 				let mut stream = tokio_stream::iter(msg.event_logs);
 				while let Some(log) = stream.next().await {
 					if self.is_target_contract(&log) && self.is_target_event(log.topics[0]) {
+						br_metrics::begin_sentry_event_span(format!(
+							"{:?}",
+							log.transaction_hash.unwrap_or_default()
+						));
 						self.process_confirmed_log(&log, false).await;
+						br_metrics::end_sentry_event_span();
 					}
 				}
+			} else {
+				self.wait_for_bootstrap_states().await;
 			}
 		}
 	}
@@ -101,6 +137,10 @@ impl<T: JsonRpcClient> Handler for RoundupRelayHandler<T> {
 					}
 					match RoundUpEventStatus::from_u8(serialized_log.status) {
 						RoundUpEventStatus::NextAuthorityCommitted => {
+							if self.client.wallet.is_observer() {
+								// an observer never checks selection or submits
+								return;
+							}
 							if !self.is_selected_relayer(serialized_log.roundup.round - 1).await {
 								// do nothing if not selected
 								return;
@@ -120,25 +160,38 @@ impl<T: JsonRpcClient> Handler for RoundupRelayHandler<T> {
 					}
 				},
 				Err(e) => {
-					log::error!(
-						target: &self.client.get_chain_name(),
-						"-[{}] Error on decoding RoundUp event ({:?}):{}",
-						sub_display_format(SUB_LOG_TARGET),
+					let msg = format!(
+						"Error on decoding RoundUp event ({:?}):{}",
 						log.transaction_hash,
-						e.to_string(),
-					);
-					sentry::capture_message(
-						format!(
-							"[{}]-[{}]-[{}] Error on decoding RoundUp event ({:?}):{}",
-							&self.client.get_chain_name(),
-							SUB_LOG_TARGET,
-							self.client.address(),
-							log.transaction_hash,
-							e
-						)
-						.as_str(),
-						sentry::Level::Error,
+						e.to_string()
 					);
+					match self.decode_error_severity.of(is_bootstrap) {
+						ErrorSeverity::Warn => log::warn!(
+							target: &self.client.get_chain_name(),
+							"-[{}] {}",
+							sub_display_format(SUB_LOG_TARGET),
+							msg,
+						),
+						ErrorSeverity::Error => {
+							log::error!(
+								target: &self.client.get_chain_name(),
+								"-[{}] {}",
+								sub_display_format(SUB_LOG_TARGET),
+								msg,
+							);
+							sentry::capture_message(
+								format!(
+									"[{}]-[{}]-[{}] {}",
+									&self.client.get_chain_name(),
+									SUB_LOG_TARGET,
+									abbreviate_address(&self.client.address()),
+									msg,
+								)
+								.as_str(),
+								sentry::Level::Error,
+							);
+						},
+					}
 				},
 			}
 		}
@@ -146,10 +199,11 @@ impl<T: JsonRpcClient> Handler for RoundupRelayHandler<T> {
 
 	fn is_target_contract(&self, log: &Log) -> bool {
 		log.address == self.client.protocol_contracts.socket.address()
+			&& self.client.metadata.event_filter.allows_contract(&log.address)
 	}
 
 	fn is_target_event(&self, topic: H256) -> bool {
-		topic == self.roundup_signature
+		topic == self.roundup_signature && self.client.metadata.event_filter.allows_event("RoundUp")
 	}
 }
 
@@ -157,8 +211,9 @@ impl<T: JsonRpcClient> RoundupRelayHandler<T> {
 	/// Instantiates a new `RoundupRelayHandler` instance.
 	pub fn new(
 		mut tx_request_senders_vec: Vec<Arc<TxRequestSender>>,
-		event_receiver: Receiver<EventMessage>,
+		event_receivers: Vec<EventReceiver>,
 		clients: Vec<Arc<EthClient<T>>>,
+		handler_config: &HandlerConfig,
 		bootstrap_shared_data: Arc<BootstrapSharedData>,
 	) -> Self {
 		// Only broadcast to external chains
@@ -188,14 +243,42 @@ impl<T: JsonRpcClient> RoundupRelayHandler<T> {
 
 		Self {
 			tx_request_senders,
-			event_receiver,
+			event_stream: merge_event_receivers(event_receivers),
+			event_stream_cursor: EventStreamCursor::new(Duration::from_millis(
+				MALFORMED_STREAM_ITEM_LOG_INTERVAL_MS,
+			)),
+			transitional_state_waiter: TransitionalStateWaiter::new(Duration::from_millis(
+				client.metadata.call_interval,
+			)),
 			client,
 			external_clients,
 			roundup_signature,
+			relayer_set: handler_config
+				.relayer_set
+				.unwrap_or(handler_config.handler_type.default_relayer_set()),
+			decode_error_severity: DecodeErrorSeverity::new(
+				handler_config.bootstrap_decode_error_severity,
+				handler_config.decode_error_severity,
+			),
 			bootstrap_shared_data,
 		}
 	}
 
+	/// Waits a single state check interval while the bootstrap states are transitional, so that
+	/// the handler never busy-spins until the states are synced. The waiting states are logged
+	/// once per log interval.
+	async fn wait_for_bootstrap_states(&mut self) {
+		if self.transitional_state_waiter.should_log(Instant::now()) {
+			log::info!(
+				target: &self.client.get_chain_name(),
+				"-[{}] ⏳ Waiting for the bootstrap states to be synced: {:?}",
+				sub_display_format(SUB_LOG_TARGET),
+				self.bootstrap_shared_data.bootstrap_states.read().await,
+			);
+		}
+		self.transitional_state_waiter.wait().await;
+	}
+
 	/// Decode & Serialize log to `SerializedRoundUp` struct.
 	async fn decode_log(&self, log: Log) -> Result<SerializedRoundUp, ethers::abi::Error> {
 		match SocketContractEvents::decode_log(&log.into()) {
@@ -238,6 +321,8 @@ impl<T: JsonRpcClient> RoundupRelayHandler<T> {
 				self.client.wallet.recover_message(sig, &encoded_msg),
 			));
 		}
+		self.client
+			.flag_untrusted_signatures(&recovered_sigs, "socket.get_round_signatures");
 		recovered_sigs.sort_by_key(|k| k.signer);
 
 		let mut sorted_sigs = Signatures::default();
@@ -259,7 +344,11 @@ impl<T: JsonRpcClient> RoundupRelayHandler<T> {
 		let relayer_manager = self.client.protocol_contracts.relayer_manager.as_ref().unwrap();
 		self.client
 			.contract_call(
-				relayer_manager.is_previous_selected_relayer(round, self.client.address(), true),
+				relayer_manager.is_previous_selected_relayer(
+					round,
+					self.client.address(),
+					self.relayer_set.is_initial(),
+				),
 				"relayer_manager.is_previous_selected_relayer",
 			)
 			.await
@@ -297,12 +386,16 @@ impl<T: JsonRpcClient> RoundupRelayHandler<T> {
 		let mut stream = tokio_stream::iter(self.external_clients.iter());
 		while let Some(target_client) = stream.next().await {
 			// Check roundup submitted to target chain before.
-			let latest_round = target_client
+			let latest_round: U256 = target_client
 				.contract_call(
 					target_client.protocol_contracts.authority.latest_round(),
 					"authority.latest_round",
 				)
 				.await;
+			if target_client.is_zeroed_value(latest_round.is_zero(), "authority.latest_round") {
+				continue;
+			}
+			br_metrics::set_current_round(&target_client.get_chain_name(), latest_round.as_u64());
 			if roundup_submit.round > latest_round {
 				let transaction_request = self.build_transaction_request(
 					&target_client.protocol_contracts.socket,
@@ -335,19 +428,24 @@ impl<T: JsonRpcClient> RoundupRelayHandler<T> {
 
 		for target_client in external_clients {
 			let barrier_clone_inner = barrier_clone.clone();
-			let current_round = self
+			let current_round: U256 = self
 				.client
 				.contract_call(
 					self.client.protocol_contracts.authority.latest_round(),
 					"authority.latest_round",
 				)
 				.await;
-			let target_chain_round = target_client
+			let target_chain_round: U256 = target_client
 				.contract_call(
 					target_client.protocol_contracts.authority.latest_round(),
 					"authority.latest_round",
 				)
 				.await;
+			br_metrics::set_current_round(&self.client.get_chain_name(), current_round.as_u64());
+			br_metrics::set_current_round(
+				&target_client.get_chain_name(),
+				target_chain_round.as_u64(),
+			);
 			let bootstrap_guard = self.bootstrap_shared_data.roundup_bootstrap_count.clone();
 
 			tokio::spawn(async move {
@@ -381,18 +479,25 @@ impl<T: JsonRpcClient> BootstrapHandler for RoundupRelayHandler<T> {
 			== self.external_clients.len() as u8
 		{
 			// set all of state to BootstrapSocket
-			for state in bootstrap_guard.iter_mut() {
+			for state in bootstrap_guard.values_mut() {
 				*state = BootstrapState::BootstrapSocketRelay;
 			}
 		}
 
-		if bootstrap_guard.iter().all(|s| *s == BootstrapState::BootstrapRoundUpPhase2) {
+		if bootstrap_guard.values().all(|s| *s == BootstrapState::BootstrapRoundUpPhase2) {
 			drop(bootstrap_guard);
 			let logs = self.get_bootstrap_events().await;
 
 			let mut stream = tokio_stream::iter(logs);
 			while let Some(log) = stream.next().await {
-				self.process_confirmed_log(&log, true).await;
+				if self.is_target_contract(&log) && self.is_target_event(log.topics[0]) {
+					br_metrics::begin_sentry_event_span(format!(
+						"{:?}",
+						log.transaction_hash.unwrap_or_default()
+					));
+					self.process_confirmed_log(&log, true).await;
+					br_metrics::end_sentry_event_span();
+				}
 			}
 		}
 
@@ -423,9 +528,8 @@ impl<T: JsonRpcClient> BootstrapHandler for RoundupRelayHandler<T> {
 				)
 				.await;
 
-			let latest_block_number = self.client.get_latest_block_number().await;
-			let mut from_block = latest_block_number.saturating_sub(bootstrap_offset_height);
-			let to_block = latest_block_number;
+			let (mut from_block, to_block) =
+				self.client.get_bootstrap_range(bootstrap_offset_height, U64::zero()).await;
 
 			// Split from_block into smaller chunks
 			while from_block <= to_block {
@@ -454,7 +558,7 @@ impl<T: JsonRpcClient> BootstrapHandler for RoundupRelayHandler<T> {
 			.bootstrap_states
 			.read()
 			.await
-			.iter()
+			.values()
 			.all(|s| *s == state)
 	}
 }
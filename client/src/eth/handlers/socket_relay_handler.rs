@@ -1,42 +1,78 @@
-use std::{collections::BTreeMap, sync::Arc, time::Duration};
+use std::{
+	collections::BTreeMap,
+	sync::{Arc, Mutex},
+	time::{Duration, Instant},
+};
 
 use ethers::{
 	abi::RawLog,
 	prelude::decode_logs,
 	providers::JsonRpcClient,
-	types::{Filter, Log, TransactionRequest, H256, U256},
+	types::{Address, Filter, Log, TransactionRequest, H256, U256, U64},
+};
+use tokio::{
+	sync::broadcast::{error::TryRecvError, Receiver},
+	time::sleep,
 };
-use tokio::{sync::broadcast::Receiver, time::sleep};
 use tokio_stream::StreamExt;
 
 use br_primitives::{
-	bootstrap::BootstrapSharedData,
+	abbreviate_address,
+	bootstrap::{
+		BootstrapEstimator, BootstrapPlan, BootstrapSharedData, LogThrottle,
+		TransitionalStateWaiter,
+	},
+	buffer::{BoundedBuffer, BufferInsertion, SheddingPolicy},
+	bus::SystemEvent,
+	checkpoint::chunk_ranges,
+	cli::HandlerConfig,
 	constants::{
 		cli::DEFAULT_BOOTSTRAP_ROUND_OFFSET,
-		config::BOOTSTRAP_BLOCK_CHUNK_SIZE,
-		errors::{INVALID_BIFROST_NATIVENESS, INVALID_CHAIN_ID, INVALID_CONTRACT_ABI},
+		config::{
+			BOOTSTRAP_BLOCK_CHUNK_SIZE, BOOTSTRAP_PROGRESS_LOG_INTERVAL_MS,
+			BOOTSTRAP_RATE_WINDOW_MS, MALFORMED_STREAM_ITEM_LOG_INTERVAL_MS,
+			RELAYED_SOCKET_TRACKING_CAPACITY,
+		},
+		errors::{
+			INVALID_BIFROST_NATIVENESS, INVALID_CHAIN_ID, INVALID_CONTRACT_ABI,
+			PROVIDER_INTERNAL_ERROR,
+		},
 	},
 	contracts::{
 		authority::RoundMetaData,
 		socket::{RequestID, Signatures, SocketEvents, SocketMessage},
 	},
+	dead_letter::DeadLetter,
 	eth::{
-		BootstrapState, BuiltRelayTransaction, ChainID, GasCoefficient, RelayDirection,
-		SocketEventStatus,
+		BootstrapState, BuiltRelayTransaction, ChainID, DecodeErrorSeverity, ErrorSeverity,
+		GasCoefficient, RelayDirection, RelayOutcome, RelayedSocketMessages, RelayerSetKind,
+		RoundStats, RoundStatsTracker, SelectionTracker, SocketEventStatus, SocketRelayKey,
 	},
 	periodic::RollbackSender,
 	sub_display_format,
-	tx::{SocketRelayMetadata, TxRequest, TxRequestMessage, TxRequestMetadata, TxRequestSender},
+	tx::{
+		PendingChunks, SocketRelayMetadata, TxRequest, TxRequestMessage, TxRequestMetadata,
+		TxRequestSender,
+	},
 };
 
 use crate::eth::{
-	events::EventMessage,
+	events::{
+		merge_event_receivers, next_event_message, refetch_dropped_logs, EventReceiver,
+		EventStream, EventStreamCursor,
+	},
 	traits::{BootstrapHandler, Handler, SocketRelayBuilder},
 	EthClient,
 };
 
 const SUB_LOG_TARGET: &str = "socket-handler";
 
+/// Returns the key that identifies the relay of the given socket message, by its request chain,
+/// sequence and status.
+fn relay_key(msg: &SocketMessage) -> SocketRelayKey {
+	(ChainID::from_be_bytes(msg.req_id.chain), msg.req_id.sequence, msg.status)
+}
+
 /// The essential task that handles `socket relay` related events.
 pub struct SocketRelayHandler<T> {
 	/// The `EthClient` to interact with the connected blockchain.
@@ -45,14 +81,43 @@ pub struct SocketRelayHandler<T> {
 	tx_request_senders: BTreeMap<ChainID, Arc<TxRequestSender>>,
 	/// The rollback senders that sends rollbackable socket messages.
 	rollback_senders: BTreeMap<ChainID, Arc<RollbackSender>>,
-	/// The receiver that consumes new events from the block channel.
-	event_receiver: Receiver<EventMessage>,
+	/// The stream that consumes new events from the block channels.
+	event_stream: EventStream,
+	/// The read position of the event stream.
+	event_stream_cursor: EventStreamCursor,
 	/// The entire clients instantiated in the system. <chain_id, Arc<EthClient>>
 	system_clients: BTreeMap<ChainID, Arc<EthClient<T>>>,
 	/// Signature of the `Socket` Event.
 	socket_signature: H256,
+	/// The relayer set used to verify whether this relayer was selected at the event's round.
+	relayer_set: RelayerSetKind,
+	/// The minimum number of selected relayers required to relay.
+	min_selected_relayers: Option<u32>,
+	/// The tracker of the latest observed selection result.
+	selection_tracker: Mutex<SelectionTracker>,
+	/// The flag whether relaying has been paused since the selected relayer set is below the
+	/// minimum.
+	is_relay_paused: Mutex<bool>,
+	/// The result of the latest minimum selected relayers check, cached per round. <round,
+	/// has_min>
+	min_selected_relayers_check: Mutex<Option<(U256, bool)>>,
+	/// The socket events deferred while relaying is paused, sorted by their position in the chain.
+	/// <(block_number, log_index), (log, is_bootstrap)>
+	deferred_logs: Mutex<BoundedBuffer<(U64, U256), (Log, bool)>>,
+	/// The relays requested on bootstrap that haven't been settled yet, per scanned chunk.
+	pending_bootstrap_requests: Mutex<PendingChunks>,
+	/// The tracker of the socket events processed within the latest observed round.
+	round_stats_tracker: Mutex<RoundStatsTracker>,
+	/// The receiver of the system events, used to finish the round stats on round transitions.
+	system_events: Receiver<SystemEvent>,
+	/// The latest relayed socket messages, used to discard events re-processed after a reorg.
+	relayed_messages: Mutex<RelayedSocketMessages>,
+	/// The severities of socket event decode errors.
+	decode_error_severity: DecodeErrorSeverity,
 	/// The bootstrap shared data.
 	bootstrap_shared_data: Arc<BootstrapSharedData>,
+	/// The waiter used while the bootstrap states are transitional.
+	transitional_state_waiter: TransitionalStateWaiter,
 }
 
 #[async_trait::async_trait]
@@ -64,7 +129,16 @@ impl<T: 'static + JsonRpcClient> Handler for SocketRelayHandler<T> {
 
 				sleep(Duration::from_millis(self.client.metadata.call_interval)).await;
 			} else if self.is_bootstrap_state_synced_as(BootstrapState::NormalStart).await {
-				let msg = self.event_receiver.recv().await.unwrap();
+				let (_, mut msg, dropped_from) = next_event_message(
+					&mut self.event_stream,
+					&mut self.event_stream_cursor,
+					&self.client.get_chain_name(),
+				)
+				.await;
+				if let Some(dropped_from) = dropped_from {
+					refetch_dropped_logs(&self.client, dropped_from, &mut msg).await;
+				}
+				self.summarize_finished_rounds();
 
 				log::info!(
 					target: &self.client.get_chain_name(),
@@ -74,12 +148,44 @@ impl<T: 'static + JsonRpcClient> Handler for SocketRelayHandler<T> {
 					msg.event_logs.len(),
 				);
 
-				let mut stream = tokio_stream::iter(msg.event_logs);
+				// the deferred events are re-processed on the same view as the imported events
+				let has_deferred_logs = !self.deferred_logs.lock().unwrap().is_empty();
+				let latest_block = if msg.event_logs.is_empty() && !has_deferred_logs {
+					None
+				} else {
+					match self.client.get_reconciled_block_number(msg.block_number).await {
+						Some(latest_block) => Some(latest_block),
+						None => {
+							// never act on a desynced view of the chain
+							msg.event_logs
+								.iter()
+								.filter(|log| {
+									self.is_target_contract(log)
+										&& self.is_target_event(log.topics[0])
+								})
+								.for_each(|log| {
+									self.defer_log(log, false, "the latest block is reconciled")
+								});
+							continue;
+						},
+					}
+				};
+				self.process_deferred_logs().await;
+
+				let logs = self.discard_stale_logs(msg.event_logs, latest_block).await;
+				let mut stream = tokio_stream::iter(logs);
 				while let Some(log) = stream.next().await {
 					if self.is_target_contract(&log) && self.is_target_event(log.topics[0]) {
+						br_metrics::begin_sentry_event_span(format!(
+							"{:?}",
+							log.transaction_hash.unwrap_or_default()
+						));
 						self.process_confirmed_log(&log, false).await;
+						br_metrics::end_sentry_event_span();
 					}
 				}
+			} else {
+				self.wait_for_bootstrap_states().await;
 			}
 		}
 	}
@@ -88,6 +194,18 @@ impl<T: 'static + JsonRpcClient> Handler for SocketRelayHandler<T> {
 		match decode_logs::<SocketEvents>(&[RawLog::from(log.clone())]) {
 			Ok(decoded) => match &decoded[0] {
 				SocketEvents::Socket(socket) => {
+					let block_number = log.block_number.unwrap_or_default();
+					if self.client.metadata.is_before_deployment(block_number) {
+						log::debug!(
+							target: &self.client.get_chain_name(),
+							"-[{}] ⏭️  Ignored socket event emitted before deployment: #{:?} < #{:?}",
+							sub_display_format(SUB_LOG_TARGET),
+							block_number,
+							self.client.metadata.deployment_block,
+						);
+						return;
+					}
+
 					let msg = socket.clone().msg;
 					let metadata = SocketRelayMetadata::new(
 						self.is_inbound_sequence(ChainID::from_be_bytes(msg.ins_code.chain)),
@@ -110,38 +228,71 @@ impl<T: 'static + JsonRpcClient> Handler for SocketRelayHandler<T> {
 						);
 					}
 
-					if !self.is_selected_relayer(&msg.req_id.round_id.into()).await {
+					let round: U256 = msg.req_id.round_id.into();
+					if self.client.wallet.is_observer() {
+						// an observer never checks selection or submits
+						self.record_round_stats(round, &msg, RelayOutcome::Observer, is_bootstrap);
+						return;
+					}
+
+					if !self.is_selected_relayer(&round).await {
 						// do nothing if not selected
+						self.record_round_stats(
+							round,
+							&msg,
+							RelayOutcome::Unselected,
+							is_bootstrap,
+						);
 						return;
 					}
 					if self.is_sequence_ended(&msg.req_id, metadata.src_chain_id).await {
 						// do nothing if protocol sequence ended
+						self.record_round_stats(
+							round,
+							&msg,
+							RelayOutcome::SequenceEnded,
+							is_bootstrap,
+						);
+						return;
+					}
+					if !self.has_min_selected_relayers(round).await {
+						// keep the event until the selected relayer set recovers
+						self.defer_log(
+							log,
+							is_bootstrap,
+							"the selected relayers reach the minimum",
+						);
 						return;
 					}
+					if self.is_relayed(&msg) {
+						// the event has been re-processed (e.g. after a deep reorg rewind)
+						log::debug!(
+							target: &self.client.get_chain_name(),
+							"-[{}] ⏭️  Ignored already relayed socket event: {}",
+							sub_display_format(SUB_LOG_TARGET),
+							metadata,
+						);
+						return;
+					}
+					self.record_round_stats(round, &msg, RelayOutcome::Relayed, is_bootstrap);
 
 					self.send_socket_message(msg.clone(), metadata.clone(), metadata.is_inbound)
 						.await;
 				},
 			},
-			Err(error) => panic!(
-				"[{}]-[{}]-[{}] Unknown error while decoding socket event: {:?}",
-				self.client.get_chain_name(),
-				SUB_LOG_TARGET,
-				self.client.address(),
-				error,
-			),
+			Err(error) => self.report_decode_error(log, error, is_bootstrap),
 		}
 	}
 
 	fn is_target_contract(&self, log: &Log) -> bool {
 		if log.address == self.client.protocol_contracts.socket.address() {
-			return true;
+			return self.client.metadata.event_filter.allows_contract(&log.address);
 		}
 		false
 	}
 
 	fn is_target_event(&self, topic: H256) -> bool {
-		topic == self.socket_signature
+		topic == self.socket_signature && self.client.metadata.event_filter.allows_event("Socket")
 	}
 }
 
@@ -196,7 +347,7 @@ impl<T: JsonRpcClient> SocketRelayBuilder<T> for SocketRelayHandler<T> {
 				"[{}]-[{}]-[{}] Unknown socket event status received: {:?}",
 				&self.client.get_chain_name(),
 				SUB_LOG_TARGET,
-				self.client.address(),
+				abbreviate_address(&self.client.address()),
 				status
 			),
 		};
@@ -220,7 +371,7 @@ impl<T: JsonRpcClient> SocketRelayBuilder<T> for SocketRelayHandler<T> {
 				"[{}]-[{}]-[{}] Unknown socket event status received: {:?}",
 				&self.client.get_chain_name(),
 				SUB_LOG_TARGET,
-				self.client.address(),
+				abbreviate_address(&self.client.address()),
 				status
 			),
 		};
@@ -234,8 +385,11 @@ impl<T: 'static + JsonRpcClient> SocketRelayHandler<T> {
 		id: ChainID,
 		tx_request_senders_vec: Vec<Arc<TxRequestSender>>,
 		rollback_senders: BTreeMap<ChainID, Arc<RollbackSender>>,
-		event_receiver: Receiver<EventMessage>,
+		event_receivers: Vec<EventReceiver>,
 		system_clients_vec: Vec<Arc<EthClient<T>>>,
+		handler_config: &HandlerConfig,
+		max_buffered_events: usize,
+		shedding_policy: SheddingPolicy,
 		bootstrap_shared_data: Arc<BootstrapSharedData>,
 	) -> Self {
 		let system_clients: BTreeMap<ChainID, Arc<EthClient<T>>> = system_clients_vec
@@ -253,7 +407,10 @@ impl<T: 'static + JsonRpcClient> SocketRelayHandler<T> {
 		Self {
 			tx_request_senders,
 			rollback_senders,
-			event_receiver,
+			event_stream: merge_event_receivers(event_receivers),
+			event_stream_cursor: EventStreamCursor::new(Duration::from_millis(
+				MALFORMED_STREAM_ITEM_LOG_INTERVAL_MS,
+			)),
 			socket_signature: client
 				.protocol_contracts
 				.socket
@@ -261,12 +418,266 @@ impl<T: 'static + JsonRpcClient> SocketRelayHandler<T> {
 				.event("Socket")
 				.expect(INVALID_CONTRACT_ABI)
 				.signature(),
+			transitional_state_waiter: TransitionalStateWaiter::new(Duration::from_millis(
+				client.metadata.call_interval,
+			)),
 			client,
 			system_clients,
+			relayer_set: handler_config
+				.relayer_set
+				.unwrap_or(handler_config.handler_type.default_relayer_set()),
+			min_selected_relayers: handler_config.min_selected_relayers,
+			selection_tracker: Mutex::new(SelectionTracker::default()),
+			is_relay_paused: Mutex::new(false),
+			min_selected_relayers_check: Mutex::new(None),
+			deferred_logs: Mutex::new(BoundedBuffer::new(max_buffered_events, shedding_policy)),
+			pending_bootstrap_requests: Mutex::new(PendingChunks::default()),
+			round_stats_tracker: Mutex::new(RoundStatsTracker::default()),
+			system_events: bootstrap_shared_data.event_bus.subscribe(),
+			relayed_messages: Mutex::new(RelayedSocketMessages::new(
+				RELAYED_SOCKET_TRACKING_CAPACITY,
+			)),
+			decode_error_severity: DecodeErrorSeverity::new(
+				handler_config.bootstrap_decode_error_severity,
+				handler_config.decode_error_severity,
+			),
 			bootstrap_shared_data,
 		}
 	}
 
+	/// Reports the socket event that failed to be decoded with the configured severity.
+	fn report_decode_error(&self, log: &Log, error: ethers::abi::Error, is_bootstrap: bool) {
+		let msg = format!(
+			"Error on decoding socket event ({:?}): {}",
+			log.transaction_hash,
+			error.to_string()
+		);
+		match self.decode_error_severity.of(is_bootstrap) {
+			ErrorSeverity::Warn => log::warn!(
+				target: &self.client.get_chain_name(),
+				"-[{}] ⚠️  {}",
+				sub_display_format(SUB_LOG_TARGET),
+				msg,
+			),
+			ErrorSeverity::Error => {
+				log::error!(
+					target: &self.client.get_chain_name(),
+					"-[{}] ❗️ {}",
+					sub_display_format(SUB_LOG_TARGET),
+					msg,
+				);
+				sentry::capture_message(
+					format!(
+						"[{}]-[{}]-[{}] ❗️ {}",
+						&self.client.get_chain_name(),
+						SUB_LOG_TARGET,
+						abbreviate_address(&self.client.address()),
+						msg,
+					)
+					.as_str(),
+					sentry::Level::Error,
+				);
+			},
+		}
+		self.route_to_dead_letter_queue(log, error.to_string(), is_bootstrap);
+	}
+
+	/// Routes the socket event that failed to be decoded to the dead letter queue, if enabled.
+	fn route_to_dead_letter_queue(&self, log: &Log, error: String, is_bootstrap: bool) {
+		let dead_letter_queue = match &self.bootstrap_shared_data.dead_letter_queue {
+			Some(dead_letter_queue) => dead_letter_queue,
+			None => return,
+		};
+
+		let letter = DeadLetter {
+			chain_id: self.client.get_chain_id(),
+			log: log.clone(),
+			error,
+			is_bootstrap,
+		};
+		let shed = match dead_letter_queue.push(&letter) {
+			Ok(BufferInsertion::Inserted) => return,
+			Ok(BufferInsertion::DroppedOldest(key)) => {
+				format!("Dropped the oldest letter: {}", key)
+			},
+			Ok(BufferInsertion::Rejected) => {
+				format!("Rejected the socket event ({:?})", log.transaction_hash)
+			},
+			Err(error) => {
+				log::warn!(
+					target: &self.client.get_chain_name(),
+					"-[{}] ⚠️  Failed to route the socket event ({:?}) to the dead letter queue: {}",
+					sub_display_format(SUB_LOG_TARGET),
+					log.transaction_hash,
+					error,
+				);
+				return;
+			},
+		};
+
+		br_metrics::increase_shed_events(&self.client.get_chain_name(), "dead_letter");
+		log::error!(
+			target: &self.client.get_chain_name(),
+			"-[{}] ❗️ Dead letter queue is full. {}",
+			sub_display_format(SUB_LOG_TARGET),
+			shed,
+		);
+		sentry::capture_message(
+			format!(
+				"[{}]-[{}]-[{}] ❗️ Dead letter queue is full. {}",
+				&self.client.get_chain_name(),
+				SUB_LOG_TARGET,
+				abbreviate_address(&self.client.address()),
+				shed,
+			)
+			.as_str(),
+			sentry::Level::Error,
+		);
+	}
+
+	/// Replays the socket events of the given block in dry-run. Each event goes through the same
+	/// decode, age and selection checks as live events, but nothing will be submitted nor
+	/// observed. Returns the per-event summaries in replay order.
+	pub async fn replay_block(&self, block_number: U64) -> Vec<String> {
+		let filter = Filter::new()
+			.address(self.client.protocol_contracts.socket.address())
+			.topic0(self.socket_signature)
+			.from_block(block_number)
+			.to_block(block_number);
+		let logs = self.client.get_logs(&filter).await;
+
+		log::info!(
+			target: &self.client.get_chain_name(),
+			"-[{}] 🔁 [Replay] #{:?} with target logs({:?})",
+			sub_display_format(SUB_LOG_TARGET),
+			block_number,
+			logs.len(),
+		);
+
+		self.replay_logs(logs).await
+	}
+
+	/// Replays the socket events of the given block range in dry-run, through the same checks as
+	/// `replay_block`. If `is_reverse` is set, the newest events are replayed first. Returns the
+	/// per-event summaries in replay order.
+	pub async fn replay_range(
+		&self,
+		from_block: U64,
+		to_block: U64,
+		is_reverse: bool,
+	) -> Vec<String> {
+		log::info!(
+			target: &self.client.get_chain_name(),
+			"-[{}] 🔁 [Replay] #{:?}..#{:?} ({})",
+			sub_display_format(SUB_LOG_TARGET),
+			from_block,
+			to_block,
+			if is_reverse { "newest first" } else { "oldest first" },
+		);
+
+		let mut summaries = vec![];
+		for (chunk_from_block, chunk_to_block) in replay_chunks(
+			from_block.as_u64(),
+			to_block.as_u64(),
+			BOOTSTRAP_BLOCK_CHUNK_SIZE,
+			is_reverse,
+		) {
+			let filter = Filter::new()
+				.address(self.client.protocol_contracts.socket.address())
+				.topic0(self.socket_signature)
+				.from_block(chunk_from_block)
+				.to_block(chunk_to_block);
+			let mut logs = self.client.get_logs(&filter).await;
+			sort_logs(&mut logs, is_reverse);
+
+			summaries.extend(self.replay_logs(logs).await);
+		}
+		summaries
+	}
+
+	/// Prints the decision that would be made on each of the given socket event logs, and returns
+	/// the printed summaries.
+	async fn replay_logs(&self, logs: Vec<Log>) -> Vec<String> {
+		// the latest block is only requested once for the given logs
+		let latest_block = match self.client.metadata.max_event_age {
+			Some(_) if !logs.is_empty() => Some(self.client.get_latest_block_number().await),
+			_ => None,
+		};
+		let mut summaries = vec![];
+		for log in logs {
+			let summary = format!(
+				"#{:?}-{:?}: {}",
+				log.block_number.unwrap_or_default(),
+				log.transaction_hash.unwrap_or_default(),
+				self.replay_decision(&log, latest_block).await,
+			);
+			log::info!(
+				target: &self.client.get_chain_name(),
+				"-[{}] 🔁 [Replay] {}",
+				sub_display_format(SUB_LOG_TARGET),
+				summary,
+			);
+			summaries.push(summary);
+		}
+		summaries
+	}
+
+	/// Returns the decision that would be made on the given socket event log. Unlike the live
+	/// path, the selection result is only queried, so the selection state isn't affected.
+	async fn replay_decision(&self, log: &Log, latest_block: Option<U64>) -> String {
+		let socket = match decode_logs::<SocketEvents>(&[RawLog::from(log.clone())]) {
+			Ok(decoded) => match &decoded[0] {
+				SocketEvents::Socket(socket) => socket.clone(),
+			},
+			Err(error) => return format!("skip (decode failed: {:?})", error),
+		};
+		let block_number = log.block_number.unwrap_or_default();
+		if self.client.metadata.is_before_deployment(block_number) {
+			return String::from("skip (emitted before deployment)");
+		}
+		if latest_block.is_some_and(|latest_block| {
+			self.client.metadata.is_stale_event(block_number, latest_block)
+		}) {
+			return String::from("skip (older than the maximum event age)");
+		}
+
+		let msg = socket.msg;
+		let metadata = SocketRelayMetadata::new(
+			self.is_inbound_sequence(ChainID::from_be_bytes(msg.ins_code.chain)),
+			SocketEventStatus::from_u8(msg.status),
+			msg.req_id.sequence,
+			ChainID::from_be_bytes(msg.req_id.chain),
+			ChainID::from_be_bytes(msg.ins_code.chain),
+			msg.params.to,
+			false,
+		);
+
+		if self.client.wallet.is_observer() {
+			return format!("{} -> skip (observer)", metadata);
+		}
+		if !self.query_selected_relayer(&msg.req_id.round_id.into()).await {
+			return format!("{} -> skip (not selected at round {})", metadata, msg.req_id.round_id);
+		}
+		if self.is_sequence_ended(&msg.req_id, metadata.src_chain_id).await {
+			return format!("{} -> skip (sequence ended)", metadata);
+		}
+
+		let relay_tx_chain_id = if metadata.is_inbound {
+			self.get_inbound_relay_tx_chain_id(
+				metadata.status,
+				metadata.src_chain_id,
+				metadata.dst_chain_id,
+			)
+		} else {
+			self.get_outbound_relay_tx_chain_id(
+				metadata.status,
+				metadata.src_chain_id,
+				metadata.dst_chain_id,
+			)
+		};
+		format!("{} -> relay to chain({:?})", metadata, relay_tx_chain_id)
+	}
+
 	/// Sends the `SocketMessage` to the target chain channel.
 	async fn send_socket_message(
 		&self,
@@ -384,39 +795,545 @@ impl<T: 'static + JsonRpcClient> SocketRelayHandler<T> {
 		)
 	}
 
-	/// Verifies whether the current relayer was selected at the given round.
+	/// Discards the logs that are older than the configured maximum event age. The latest block is
+	/// only requested once for the given logs, unless it's already known.
+	async fn discard_stale_logs(&self, logs: Vec<Log>, latest_block: Option<U64>) -> Vec<Log> {
+		if self.client.metadata.max_event_age.is_none() || logs.is_empty() {
+			return logs;
+		}
+
+		let latest_block = match latest_block {
+			Some(latest_block) => latest_block,
+			None => self.client.get_latest_block_number().await,
+		};
+		let (stale_logs, logs): (Vec<Log>, Vec<Log>) = logs.into_iter().partition(|log| {
+			self.client
+				.metadata
+				.is_stale_event(log.block_number.unwrap_or_default(), latest_block)
+		});
+
+		if !stale_logs.is_empty() {
+			log::warn!(
+				target: &self.client.get_chain_name(),
+				"-[{}] ⏭️  Discarded {} socket event(s) older than {:?} blocks (best: #{:?})",
+				sub_display_format(SUB_LOG_TARGET),
+				stale_logs.len(),
+				self.client.metadata.max_event_age.unwrap_or_default(),
+				latest_block,
+			);
+		}
+		logs
+	}
+
+	/// Returns the block range to bootstrap, floored at the deployment block of the socket
+	/// contract. Returns `None` if bootstrap hasn't been configured, and an error if the round
+	/// couldn't be fetched from the native chain.
+	async fn get_bootstrap_range(&self) -> Result<Option<(U64, U64)>, String> {
+		let bootstrap_config = match self.bootstrap_shared_data.bootstrap_config.as_ref() {
+			Some(bootstrap_config) => bootstrap_config,
+			None => return Ok(None),
+		};
+
+		let native_client = self.get_native_client();
+		let round_info: RoundMetaData = native_client
+			.try_contract_call(native_client.protocol_contracts.authority.round_info())
+			.await?;
+		if native_client.is_zeroed_value(round_info.is_zeroed(), "authority.round_info") {
+			return Err(String::from("authority.round_info returned a zeroed value"));
+		}
+
+		if let Some(tolerance) = bootstrap_config.round_drift_tolerance {
+			self.verify_round_consistency(native_client, round_info.current_round_index, tolerance)
+				.await;
+		}
+
+		let bootstrap_offset_height = self
+			.client
+			.get_bootstrap_offset_height_based_on_block_time(
+				bootstrap_config.round_offset.unwrap_or(DEFAULT_BOOTSTRAP_ROUND_OFFSET),
+				round_info,
+			)
+			.await;
+
+		Ok(Some(
+			self.client
+				.get_bootstrap_range(bootstrap_offset_height, self.client.metadata.deployment_block)
+				.await,
+		))
+	}
+
+	/// Returns the bootstrap plan of this chain. Returns `None` if bootstrap hasn't been
+	/// configured, and an error if the round couldn't be fetched from the native chain.
+	pub async fn get_bootstrap_plan(&self) -> Result<Option<BootstrapPlan>, String> {
+		let (from_block, to_block) = match self.get_bootstrap_range().await? {
+			Some(range) => range,
+			None => return Ok(None),
+		};
+		let checkpoint = self
+			.bootstrap_shared_data
+			.checkpoint_store
+			.as_ref()
+			.and_then(|store| store.load(self.client.get_chain_id()));
+
+		Ok(Some(BootstrapPlan::new(
+			from_block.as_u64(),
+			to_block.as_u64(),
+			checkpoint,
+			BOOTSTRAP_BLOCK_CHUNK_SIZE,
+		)))
+	}
+
+	/// Fetches the socket event logs of a single bootstrap chunk.
+	async fn get_bootstrap_chunk_events(&self, from_block: u64, to_block: u64) -> Vec<Log> {
+		let filter = Filter::new()
+			.address(self.client.protocol_contracts.socket.address())
+			.topic0(self.socket_signature)
+			.from_block(from_block)
+			.to_block(to_block);
+		self.client.get_logs(&filter).await
+	}
+
+	/// Verifies whether the latest round of `relayer_manager` (used for selection) and `authority`
+	/// (used for the bootstrap offset) agree within the given tolerance. A divergence signals an
+	/// inconsistent contract state, so only a warning will be emitted.
+	async fn verify_round_consistency(
+		&self,
+		native_client: &EthClient<T>,
+		authority_round: U256,
+		tolerance: u32,
+	) {
+		let relayer_manager = native_client.protocol_contracts.relayer_manager.as_ref().unwrap();
+		let relayer_manager_round: U256 =
+			match native_client.try_contract_call(relayer_manager.latest_round()).await {
+				Ok(round) => round,
+				Err(error) => {
+					log::warn!(
+						target: &self.client.get_chain_name(),
+						"-[{}] ⚠️  Skipped the round cross-check: the native chain is unavailable: {}",
+						sub_display_format(SUB_LOG_TARGET),
+						error,
+					);
+					return;
+				},
+			};
+
+		let drift = if relayer_manager_round > authority_round {
+			relayer_manager_round - authority_round
+		} else {
+			authority_round - relayer_manager_round
+		};
+		if drift > U256::from(tolerance) {
+			let msg = format!(
+				"Round mismatch between relayer_manager({}) and authority({}) exceeds tolerance({})",
+				relayer_manager_round, authority_round, tolerance,
+			);
+			log::warn!(
+				target: &self.client.get_chain_name(),
+				"-[{}] ⚠️  {}",
+				sub_display_format(SUB_LOG_TARGET),
+				msg,
+			);
+			sentry::capture_message(
+				format!(
+					"[{}]-[{}]-[{}] ⚠️  {}",
+					&self.client.get_chain_name(),
+					SUB_LOG_TARGET,
+					abbreviate_address(&self.client.address()),
+					msg,
+				)
+				.as_str(),
+				sentry::Level::Warning,
+			);
+		}
+	}
+
+	/// Returns the client of the native chain.
+	fn get_native_client(&self) -> &Arc<EthClient<T>> {
+		if self.client.metadata.is_native {
+			&self.client
+		} else if let Some((_id, native_client)) =
+			self.system_clients.iter().find(|(_id, client)| client.metadata.is_native)
+		{
+			native_client
+		} else {
+			panic!(
+				"[{}]-[{}] {}",
+				self.client.get_chain_name(),
+				SUB_LOG_TARGET,
+				INVALID_BIFROST_NATIVENESS,
+			);
+		}
+	}
+
+	/// Returns whether the selected relayer set has reached the configured minimum, since a
+	/// degraded set may not be able to safely reach quorum. Only the trusted relayers are counted,
+	/// if a trusted set has been configured. A set that couldn't be fetched is considered degraded.
+	/// The result is cached per round, and the alert is only emitted once per pause.
+	async fn has_min_selected_relayers(&self, round: U256) -> bool {
+		let min_selected_relayers = match self.min_selected_relayers {
+			Some(min_selected_relayers) => min_selected_relayers,
+			None => return true,
+		};
+		let cached = *self.min_selected_relayers_check.lock().unwrap();
+		if let Some((checked_round, has_min)) = cached {
+			if checked_round == round {
+				return has_min;
+			}
+		}
+		let native_client = self.get_native_client();
+		let selected_relayers: Result<Vec<Address>, String> =
+			match native_client.protocol_contracts.relayer_manager.as_ref() {
+				Some(relayer_manager) => {
+					native_client
+						.try_contract_call(
+							relayer_manager.selected_relayers(self.relayer_set.is_initial()),
+						)
+						.await
+				},
+				None => Err(String::from("relayer_manager is not configured")),
+			};
+
+		// a set that couldn't be fetched is re-fetched on the next check
+		let selected_relayers_fetched = selected_relayers.is_ok();
+		let (has_min, reason) = match selected_relayers {
+			Ok(selected_relayers) => {
+				// untrusted relayers can't be relied on to reach quorum.
+				let trusted_relayers = selected_relayers
+					.iter()
+					.filter(|relayer| native_client.metadata.is_trusted_signer(relayer))
+					.count();
+				let has_min = trusted_relayers >= min_selected_relayers as usize;
+				(
+					has_min,
+					format!(
+						"selected relayers({}) {} the minimum({})",
+						trusted_relayers,
+						if has_min { "reached" } else { "below" },
+						min_selected_relayers,
+					),
+				)
+			},
+			Err(error) => {
+				(false, format!("failed to fetch relayer_manager.selected_relayers: {}", error))
+			},
+		};
+		if selected_relayers_fetched {
+			*self.min_selected_relayers_check.lock().unwrap() = Some((round, has_min));
+		}
+
+		let was_paused = std::mem::replace(&mut *self.is_relay_paused.lock().unwrap(), !has_min);
+		match (was_paused, has_min) {
+			(true, true) => {
+				log::info!(
+					target: &self.client.get_chain_name(),
+					"-[{}] ▶️  Resumed relaying: {}",
+					sub_display_format(SUB_LOG_TARGET),
+					reason,
+				);
+				self.bootstrap_shared_data.event_bus.publish(SystemEvent::RelayResumed {
+					chain_id: self.client.get_chain_id(),
+					reason,
+				});
+			},
+			(false, false) => {
+				let msg = format!("Paused relaying: {}", reason);
+				self.bootstrap_shared_data.event_bus.publish(SystemEvent::RelayPaused {
+					chain_id: self.client.get_chain_id(),
+					reason,
+				});
+				log::error!(
+					target: &self.client.get_chain_name(),
+					"-[{}] ⏸️  {}",
+					sub_display_format(SUB_LOG_TARGET),
+					msg,
+				);
+				sentry::capture_message(
+					format!(
+						"[{}]-[{}]-[{}] ⏸️  {}",
+						&self.client.get_chain_name(),
+						SUB_LOG_TARGET,
+						abbreviate_address(&self.client.address()),
+						msg,
+					)
+					.as_str(),
+					sentry::Level::Error,
+				);
+			},
+			_ => {},
+		}
+		has_min
+	}
+
+	/// Defers the given socket event until the given condition holds, which is re-checked when
+	/// the deferred events are re-processed. Once the deferred events reach the buffer limit, the
+	/// configured shedding policy decides which event is shed, and it's alerted as an error.
+	fn defer_log(&self, log: &Log, is_bootstrap: bool, until: &str) {
+		let key = (log.block_number.unwrap_or_default(), log.log_index.unwrap_or_default());
+		let insertion = self.deferred_logs.lock().unwrap().insert(key, (log.clone(), is_bootstrap));
+		let shed = match insertion {
+			BufferInsertion::Inserted => {
+				log::info!(
+					target: &self.client.get_chain_name(),
+					"-[{}] ⏳ Deferred socket event ({:?}) until {}",
+					sub_display_format(SUB_LOG_TARGET),
+					log.transaction_hash,
+					until,
+				);
+				return;
+			},
+			BufferInsertion::DroppedOldest((block_number, log_index)) => format!(
+				"Dropped the oldest deferred socket event (#{:?}-{:?})",
+				block_number, log_index
+			),
+			BufferInsertion::Rejected => {
+				format!("Rejected the socket event ({:?})", log.transaction_hash)
+			},
+		};
+
+		br_metrics::increase_shed_events(&self.client.get_chain_name(), "deferred");
+		log::error!(
+			target: &self.client.get_chain_name(),
+			"-[{}] ❗️ Deferred socket events are full. {}",
+			sub_display_format(SUB_LOG_TARGET),
+			shed,
+		);
+		sentry::capture_message(
+			format!(
+				"[{}]-[{}]-[{}] ❗️ Deferred socket events are full. {}",
+				&self.client.get_chain_name(),
+				SUB_LOG_TARGET,
+				abbreviate_address(&self.client.address()),
+				shed,
+			)
+			.as_str(),
+			sentry::Level::Error,
+		);
+	}
+
+	/// Re-processes the deferred socket events in order, once the latest block has been
+	/// reconciled and the selected relayer set has reached the minimum again at the latest
+	/// observed round.
+	async fn process_deferred_logs(&self) {
+		let is_empty = self.deferred_logs.lock().unwrap().is_empty();
+		if is_empty {
+			return;
+		}
+		let latest_round = self.selection_tracker.lock().unwrap().latest_round();
+		if let Some(latest_round) = latest_round {
+			if !self.has_min_selected_relayers(latest_round).await {
+				return;
+			}
+		}
+
+		let deferred_logs = self.deferred_logs.lock().unwrap().drain();
+		for (_, (log, is_bootstrap)) in deferred_logs {
+			self.process_confirmed_log(&log, is_bootstrap).await;
+		}
+	}
+
+	/// Verifies whether the current relayer was selected at the given round, and observes the
+	/// result.
 	async fn is_selected_relayer(&self, round: &U256) -> bool {
+		let is_selected = self.query_selected_relayer(round).await;
+		self.observe_selection(*round, is_selected);
+		is_selected
+	}
+
+	/// Queries whether the current relayer was selected at the given round, without observing
+	/// the result.
+	async fn query_selected_relayer(&self, round: &U256) -> bool {
 		if self.client.metadata.is_native {
 			let relayer_manager = self.client.protocol_contracts.relayer_manager.as_ref().unwrap();
-			return self
-				.client
+			self.client
 				.contract_call(
 					relayer_manager.is_previous_selected_relayer(
 						*round,
 						self.client.address(),
-						false,
+						self.relayer_set.is_initial(),
 					),
 					"relayer_manager.is_previous_selected_relayer",
 				)
-				.await;
+				.await
 		} else if let Some((_id, native_client)) =
 			self.system_clients.iter().find(|(_id, client)| client.metadata.is_native)
 		{
 			// always use the native client's contract. due to handle missed VSP's.
 			let relayer_manager =
 				native_client.protocol_contracts.relayer_manager.as_ref().unwrap();
-			return native_client
+			native_client
 				.contract_call(
 					relayer_manager.is_previous_selected_relayer(
 						*round,
 						self.client.address(),
-						false,
+						self.relayer_set.is_initial(),
 					),
 					"relayer_manager.is_previous_selected_relayer",
 				)
-				.await;
+				.await
+		} else {
+			false
+		}
+	}
+
+	/// Observes the selection result of the given round, and notifies when the relayer has
+	/// entered or left the selected relayer set.
+	fn observe_selection(&self, round: U256, is_selected: bool) {
+		let (transition, latest_round) = {
+			let mut selection_tracker = self.selection_tracker.lock().unwrap();
+			let transition = selection_tracker.observe(round, is_selected);
+			(transition, selection_tracker.latest_round().unwrap_or(round))
+		};
+		br_metrics::set_current_round(&self.client.get_chain_name(), latest_round.as_u64());
+		self.bootstrap_shared_data.health_state.report_selection(
+			&self.client.get_chain_name(),
+			round.as_u64(),
+			is_selected,
+		);
+
+		if let Some(is_selected) = transition {
+			log::info!(
+				target: &self.client.get_chain_name(),
+				"-[{}] {} Relayer {} the selected relayer set at round({})",
+				sub_display_format(SUB_LOG_TARGET),
+				if is_selected { "🟢" } else { "🔴" },
+				if is_selected { "entered" } else { "left" },
+				round,
+			);
+			br_metrics::increase_selection_transitions(&self.client.get_chain_name(), is_selected);
+			self.bootstrap_shared_data.event_bus.publish(SystemEvent::SelectionChanged {
+				chain_id: self.client.get_chain_id(),
+				round,
+				is_selected,
+			});
+		}
+	}
+
+	/// Waits a single state check interval while the bootstrap states are transitional, so that
+	/// the handler never busy-spins until the states are synced. The waiting states are logged
+	/// once per log interval.
+	async fn wait_for_bootstrap_states(&mut self) {
+		if self.transitional_state_waiter.should_log(Instant::now()) {
+			log::info!(
+				target: &self.client.get_chain_name(),
+				"-[{}] ⏳ Waiting for the bootstrap states to be synced: {:?}",
+				sub_display_format(SUB_LOG_TARGET),
+				self.bootstrap_shared_data.bootstrap_states.read().await,
+			);
+		}
+		self.transitional_state_waiter.wait().await;
+	}
+
+	/// Returns `true` if the given socket message has already been relayed.
+	fn is_relayed(&self, msg: &SocketMessage) -> bool {
+		self.relayed_messages.lock().unwrap().contains(&relay_key(msg))
+	}
+
+	/// Records the outcome of the given live socket event. Emits the summary of the finished round
+	/// once the observed round has advanced. Bootstrapped events are not recorded.
+	fn record_round_stats(
+		&self,
+		round: U256,
+		msg: &SocketMessage,
+		outcome: RelayOutcome,
+		is_bootstrap: bool,
+	) {
+		if is_bootstrap {
+			return;
+		}
+
+		let finished = self.round_stats_tracker.lock().unwrap().record(
+			round,
+			H256::from(msg.params.token_idx0),
+			msg.params.amount,
+			outcome,
+		);
+		self.log_round_summary(finished);
+	}
+
+	/// Emits the summary of every round finished by the round transitions published since the
+	/// last check.
+	fn summarize_finished_rounds(&mut self) {
+		loop {
+			match self.system_events.try_recv() {
+				Ok(SystemEvent::RoundAdvanced { round, .. }) => {
+					let finished = self.round_stats_tracker.lock().unwrap().advance(round);
+					self.log_round_summary(finished);
+				},
+				Ok(_) | Err(TryRecvError::Lagged(_)) => continue,
+				Err(TryRecvError::Empty) | Err(TryRecvError::Closed) => return,
+			}
+		}
+	}
+
+	/// Emits the summary of the given finished round.
+	fn log_round_summary(&self, finished: Option<RoundStats>) {
+		if let Some(finished) = finished {
+			log::info!(
+				target: &self.client.get_chain_name(),
+				"-[{}] 🧾 Round summary: {}",
+				sub_display_format(SUB_LOG_TARGET),
+				finished,
+			);
+		}
+	}
+
+	/// Closes the scanned chunk at the given block, and advances the bootstrap checkpoint of this
+	/// chain to the latest chunk boundary that every relay requested up to has been settled. The
+	/// scan never waits for the relays, so an interrupted bootstrap only re-scans the chunks whose
+	/// relays haven't been finished. The checkpoint doesn't advance while any event is deferred,
+	/// since those haven't been relayed yet.
+	fn advance_checkpoint(&self, block_number: u64) {
+		let store = match &self.bootstrap_shared_data.checkpoint_store {
+			Some(store) => store,
+			None => return,
+		};
+
+		let settled_boundary = {
+			let mut pending_chunks = self.pending_bootstrap_requests.lock().unwrap();
+			pending_chunks.close(block_number);
+			if !self.deferred_logs.lock().unwrap().is_empty() {
+				return;
+			}
+			pending_chunks.take_settled_boundary()
+		};
+		let settled_boundary = match settled_boundary {
+			Some(settled_boundary) => settled_boundary,
+			None => return,
+		};
+
+		if let Err(error) = store.save(self.client.get_chain_id(), settled_boundary) {
+			log::warn!(
+				target: &self.client.get_chain_name(),
+				"-[{}] ⚠️  [Bootstrap mode] Failed to store checkpoint #{:?}: {}",
+				sub_display_format(SUB_LOG_TARGET),
+				settled_boundary,
+				error,
+			);
+		}
+	}
+
+	/// Clears the bootstrap checkpoint of this chain once the bootstrap has been completed, so
+	/// that the next bootstrap scans its whole window again. The checkpoint is kept if any relay
+	/// requested on bootstrap hasn't been settled yet.
+	fn clear_checkpoint(&self) {
+		if let Some(store) = &self.bootstrap_shared_data.checkpoint_store {
+			if !self.pending_bootstrap_requests.lock().unwrap().is_settled() {
+				log::info!(
+					target: &self.client.get_chain_name(),
+					"-[{}] 📌 [Bootstrap mode] Kept the checkpoint: bootstrap relays are still pending",
+					sub_display_format(SUB_LOG_TARGET),
+				);
+				return;
+			}
+			if let Err(error) = store.clear(self.client.get_chain_id()) {
+				log::warn!(
+					target: &self.client.get_chain_name(),
+					"-[{}] ⚠️  [Bootstrap mode] Failed to clear checkpoint: {}",
+					sub_display_format(SUB_LOG_TARGET),
+					error,
+				);
+			}
 		}
-		false
 	}
 
 	/// Request send socket relay transaction to the target event channel.
@@ -430,25 +1347,37 @@ impl<T: 'static + JsonRpcClient> SocketRelayHandler<T> {
 		gas_coefficient: GasCoefficient,
 	) {
 		if let Some(sender) = self.tx_request_senders.get(&chain_id) {
+			let relay_key = relay_key(&socket_msg);
 			if self.is_executable(metadata.is_inbound, metadata.status) {
 				self.send_rollbackable_request(chain_id, metadata.clone(), socket_msg);
 			}
 
-			match sender.send(TxRequestMessage::new(
+			let mut msg = TxRequestMessage::new(
 				TxRequest::Legacy(tx_request),
 				TxRequestMetadata::SocketRelay(metadata.clone()),
 				true,
 				give_random_delay,
 				gas_coefficient,
 				metadata.is_bootstrap,
-			)) {
-				Ok(()) => log::info!(
-					target: &self.client.get_chain_name(),
-					"-[{}] 🔖 Request relay transaction to chain({:?}): {}",
-					sub_display_format(SUB_LOG_TARGET),
-					chain_id,
-					metadata
-				),
+			);
+			if metadata.is_bootstrap {
+				// the bootstrap checkpoint only advances once the relay has been settled
+				msg =
+					msg.with_pending_guard(self.pending_bootstrap_requests.lock().unwrap().track());
+			}
+
+			match sender.send(msg) {
+				Ok(()) => {
+					// only a requested relay is remembered, so that a failed one can be retried
+					self.relayed_messages.lock().unwrap().insert(relay_key);
+					log::info!(
+						target: &self.client.get_chain_name(),
+						"-[{}] 🔖 Request relay transaction to chain({:?}): {}",
+						sub_display_format(SUB_LOG_TARGET),
+						chain_id,
+						metadata
+					)
+				},
 				Err(error) => {
 					log::error!(
 						target: &self.client.get_chain_name(),
@@ -463,7 +1392,7 @@ impl<T: 'static + JsonRpcClient> SocketRelayHandler<T> {
 							"[{}]-[{}]-[{}] ❗️ Failed to send relay transaction to chain({:?}): {}, Error: {}",
 							&self.client.get_chain_name(),
 							SUB_LOG_TARGET,
-							self.client.address(),
+							abbreviate_address(&self.client.address()),
 							chain_id,
 							metadata,
 							error
@@ -505,7 +1434,7 @@ impl<T: 'static + JsonRpcClient> SocketRelayHandler<T> {
 							"[{}]-[{}]-[{}] ❗️ Failed to store rollbackable socket message: {}, Error: {}",
 							&self.client.get_chain_name(),
 							SUB_LOG_TARGET,
-							self.client.address(),
+							abbreviate_address(&self.client.address()),
 							metadata,
 							error
 						)
@@ -527,21 +1456,99 @@ impl<T: 'static + JsonRpcClient> BootstrapHandler for SocketRelayHandler<T> {
 			sub_display_format(SUB_LOG_TARGET),
 		);
 
-		let logs = self.get_bootstrap_events().await;
+		let plan = match self.get_bootstrap_plan().await {
+			Ok(plan) => plan,
+			Err(error) => {
+				// retry on the next cycle, other chains are not blocked
+				log::warn!(
+					target: &self.client.get_chain_name(),
+					"-[{}] ⚠️  [Bootstrap mode] Deferred bootstrap: the native chain is unavailable or inconsistent: {}",
+					sub_display_format(SUB_LOG_TARGET),
+					error,
+				);
+				return;
+			},
+		};
+		if let Some(plan) = plan {
+			log::info!(
+				target: &self.client.get_chain_name(),
+				"-[{}] 📋 [Bootstrap mode] Plan: {}",
+				sub_display_format(SUB_LOG_TARGET),
+				plan,
+			);
+			self.bootstrap_shared_data.event_bus.publish(SystemEvent::BootstrapStarted {
+				chain_id: self.client.get_chain_id(),
+				plan: plan.to_string(),
+			});
+
+			let chunks = plan.chunks();
+			let mut estimator = BootstrapEstimator::new(
+				chunks.iter().map(|(from, to)| to + 1 - from).sum(),
+				Duration::from_millis(BOOTSTRAP_RATE_WINDOW_MS),
+				Instant::now(),
+			);
+			let mut progress_log_throttle =
+				LogThrottle::new(Duration::from_millis(BOOTSTRAP_PROGRESS_LOG_INTERVAL_MS));
+			let mut scanned_blocks = 0;
+
+			// only a single chunk of events is held at once
+			for (chunk_from_block, chunk_to_block) in chunks {
+				let logs = self
+					.discard_stale_logs(
+						self.get_bootstrap_chunk_events(chunk_from_block, chunk_to_block).await,
+						None,
+					)
+					.await;
+
+				let mut stream = tokio_stream::iter(logs);
+				while let Some(log) = stream.next().await {
+					if self.is_target_contract(&log) && self.is_target_event(log.topics[0]) {
+						br_metrics::begin_sentry_event_span(format!(
+							"{:?}",
+							log.transaction_hash.unwrap_or_default()
+						));
+						self.process_confirmed_log(&log, true).await;
+						br_metrics::end_sentry_event_span();
+					}
+				}
+
+				self.advance_checkpoint(chunk_to_block);
 
-		let mut stream = tokio_stream::iter(logs);
-		while let Some(log) = stream.next().await {
-			self.process_confirmed_log(&log, true).await;
+				scanned_blocks += chunk_to_block + 1 - chunk_from_block;
+				estimator.record(Instant::now(), scanned_blocks);
+				self.bootstrap_shared_data.health_state.report_bootstrap_progress(
+					&self.client.get_chain_name(),
+					estimator.to_string(),
+				);
+				if progress_log_throttle.should_log(Instant::now()) {
+					log::info!(
+						target: &self.client.get_chain_name(),
+						"-[{}] ⏱️  [Bootstrap mode] Progress: {}",
+						sub_display_format(SUB_LOG_TARGET),
+						estimator,
+					);
+				}
+			}
+			self.clear_checkpoint();
 		}
 
 		let mut bootstrap_count = self.bootstrap_shared_data.socket_bootstrap_count.lock().await;
 		*bootstrap_count += 1;
 
+		self.bootstrap_shared_data
+			.event_bus
+			.publish(SystemEvent::BootstrapCompleted { chain_id: self.client.get_chain_id() });
+		log::info!(
+			target: &self.client.get_chain_name(),
+			"-[{}] ⚙️  [Bootstrap mode] Bootstrap process completed on this chain.",
+			sub_display_format(SUB_LOG_TARGET),
+		);
+
 		// If All thread complete the task, starts the blockManager
 		if *bootstrap_count == self.system_clients.len() as u8 {
 			let mut bootstrap_guard = self.bootstrap_shared_data.bootstrap_states.write().await;
 
-			for state in bootstrap_guard.iter_mut() {
+			for state in bootstrap_guard.values_mut() {
 				*state = BootstrapState::NormalStart;
 			}
 
@@ -556,58 +1563,26 @@ impl<T: 'static + JsonRpcClient> BootstrapHandler for SocketRelayHandler<T> {
 	async fn get_bootstrap_events(&self) -> Vec<Log> {
 		let mut logs = vec![];
 
-		if let Some(bootstrap_config) = &self.bootstrap_shared_data.bootstrap_config {
-			let round_info: RoundMetaData = if self.client.metadata.is_native {
-				self.client
-					.contract_call(
-						self.client.protocol_contracts.authority.round_info(),
-						"authority.round_info",
-					)
-					.await
-			} else if let Some((_id, native_client)) =
-				self.system_clients.iter().find(|(_id, client)| client.metadata.is_native)
-			{
-				native_client
-					.contract_call(
-						native_client.protocol_contracts.authority.round_info(),
-						"authority.round_info",
-					)
-					.await
-			} else {
-				panic!(
-					"[{}]-[{}] {}",
-					self.client.get_chain_name(),
-					SUB_LOG_TARGET,
-					INVALID_BIFROST_NATIVENESS,
+		let range = self.get_bootstrap_range().await.unwrap_or_else(|error| {
+			panic!(
+				"[{}]-[{}]-[{}] {} [method: authority.round_info]: {}",
+				&self.client.get_chain_name(),
+				SUB_LOG_TARGET,
+				abbreviate_address(&self.client.address()),
+				PROVIDER_INTERNAL_ERROR,
+				error
+			)
+		});
+		if let Some((from_block, to_block)) = range {
+			for (chunk_from_block, chunk_to_block) in chunk_ranges(
+				from_block.as_u64(),
+				to_block.as_u64(),
+				BOOTSTRAP_BLOCK_CHUNK_SIZE,
+				None,
+			) {
+				logs.extend(
+					self.get_bootstrap_chunk_events(chunk_from_block, chunk_to_block).await,
 				);
-			};
-
-			let bootstrap_offset_height = self
-				.client
-				.get_bootstrap_offset_height_based_on_block_time(
-					bootstrap_config.round_offset.unwrap_or(DEFAULT_BOOTSTRAP_ROUND_OFFSET),
-					round_info,
-				)
-				.await;
-
-			let latest_block_number = self.client.get_latest_block_number().await;
-			let mut from_block = latest_block_number.saturating_sub(bootstrap_offset_height);
-			let to_block = latest_block_number;
-
-			// Split from_block into smaller chunks
-			while from_block <= to_block {
-				let chunk_to_block =
-					std::cmp::min(from_block + BOOTSTRAP_BLOCK_CHUNK_SIZE - 1, to_block);
-
-				let filter = Filter::new()
-					.address(self.client.protocol_contracts.socket.address())
-					.topic0(self.socket_signature)
-					.from_block(from_block)
-					.to_block(chunk_to_block);
-				let target_logs_chunk = self.client.get_logs(&filter).await;
-				logs.extend(target_logs_chunk);
-
-				from_block = chunk_to_block + 1;
 			}
 		}
 
@@ -619,25 +1594,455 @@ impl<T: 'static + JsonRpcClient> BootstrapHandler for SocketRelayHandler<T> {
 			.bootstrap_states
 			.read()
 			.await
-			.iter()
+			.values()
 			.all(|s| *s == state)
 	}
 }
 
+/// Splits the given block range into the chunks to replay. If `is_reverse` is set, the newest
+/// chunk comes first.
+fn replay_chunks(
+	from_block: u64,
+	to_block: u64,
+	chunk_size: u64,
+	is_reverse: bool,
+) -> Vec<(u64, u64)> {
+	let mut chunks = chunk_ranges(from_block, to_block, chunk_size, None);
+	if is_reverse {
+		chunks.reverse();
+	}
+	chunks
+}
+
+/// Sorts the given logs in chronological order, or in reverse chronological order if
+/// `is_reverse` is set.
+fn sort_logs(logs: &mut [Log], is_reverse: bool) {
+	logs.sort_by_key(|log| (log.block_number, log.log_index));
+	if is_reverse {
+		logs.reverse();
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use std::{str::FromStr, sync::Arc};
 
 	use ethers::{
 		abi::ParamType,
-		providers::{Http, Provider},
-		types::{Bytes, H160},
+		providers::{Http, MockProvider, Provider},
+		types::{BlockId, BlockNumber, Bytes, H160},
+	};
+	use tokio::sync::{Barrier, Mutex as AsyncMutex, RwLock};
+
+	use br_primitives::{
+		bus::SystemEventBus,
+		checkpoint::CheckpointStore,
+		cli::BootstrapConfig,
+		constants::cli::DEFAULT_MAX_BUFFERED_EVENTS,
+		contracts::socket::SocketContract,
+		eth::ProviderMetadata,
+		health::HealthState,
+		store::{MemoryStateStore, StateStore},
 	};
 
-	use br_primitives::contracts::socket::SocketContract;
+	use crate::eth::{mock_client, wallet::WalletManager};
 
 	use super::*;
 
+	/// The data of a `Socket` event of a rollbacked request from chain(3068) to chain(56), at
+	/// round(809).
+	const SOCKET_EVENT_DATA: &str = "0x000000000000000000000000000000000000000000000000000000000000002000000bfc00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000032900000000000000000000000000000000000000000000000000000000000010fe00000000000000000000000000000000000000000000000000000000000000080000003800000000000000000000000000000000000000000000000000000000040207030100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000e0000000050000000300000bfc872b347cd764d46c127ffefbcab605fff3f3a48c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000007ac737b14b926f5fbbfb7bfa1dfcb01659da1e230000000000000000000000007ac737b14b926f5fbbfb7bfa1dfcb01659da1e2300000000000000000000000000000000000000000000000075d86ab5ce70b78000000000000000000000000000000000000000000000000000000000000000c00000000000000000000000000000000000000000000000000000000000000000";
+
+	/// Builds the bootstrap shared data of a single chain(3068) with the given checkpoint store.
+	fn bootstrap_shared_data(checkpoint_store: Option<CheckpointStore>) -> BootstrapSharedData {
+		BootstrapSharedData {
+			socket_barrier: Arc::new(Barrier::new(1)),
+			roundup_barrier: Arc::new(Barrier::new(1)),
+			socket_bootstrap_count: Arc::new(AsyncMutex::new(0)),
+			roundup_bootstrap_count: Arc::new(AsyncMutex::new(0)),
+			bootstrap_states: Arc::new(RwLock::new(BTreeMap::from([(
+				3068,
+				BootstrapState::BootstrapSocketRelay,
+			)]))),
+			bootstrap_config: None,
+			event_bus: SystemEventBus::new(),
+			checkpoint_store,
+			health_state: Arc::new(HealthState::default()),
+			dead_letter_queue: None,
+		}
+	}
+
+	/// Builds an observer socket handler of the native chain(3068) whose provider never
+	/// responds. Only the handler paths that don't reach the provider can be driven.
+	fn socket_handler(
+		bootstrap_shared_data: BootstrapSharedData,
+	) -> SocketRelayHandler<MockProvider> {
+		socket_handler_with(
+			bootstrap_shared_data,
+			WalletManager::observer(3068),
+			native_metadata(),
+			MockProvider::new(),
+		)
+	}
+
+	/// Returns the provider metadata of the native chain(3068).
+	fn native_metadata() -> ProviderMetadata {
+		ProviderMetadata::new(String::from("bifrost"), 3068, 0, 10, 1, true)
+	}
+
+	/// Builds a socket handler of the native chain(3068) with the given wallet and metadata,
+	/// whose provider responds with the responses pushed to the given mock.
+	fn socket_handler_with(
+		bootstrap_shared_data: BootstrapSharedData,
+		wallet: WalletManager,
+		metadata: ProviderMetadata,
+		mock: MockProvider,
+	) -> SocketRelayHandler<MockProvider> {
+		let client = Arc::new(mock_client(wallet, metadata, mock));
+		let handler_config: HandlerConfig = serde_json::from_value(
+			serde_json::json!({ "handler_type": "Socket", "watch_list": [3068] }),
+		)
+		.unwrap();
+
+		SocketRelayHandler::new(
+			3068,
+			vec![],
+			BTreeMap::new(),
+			vec![],
+			vec![client],
+			&handler_config,
+			DEFAULT_MAX_BUFFERED_EVENTS,
+			SheddingPolicy::default(),
+			Arc::new(bootstrap_shared_data),
+		)
+	}
+
+	#[tokio::test(start_paused = true)]
+	async fn replay_only_queries_the_selection() {
+		let mock = MockProvider::new();
+		let handler = socket_handler_with(
+			bootstrap_shared_data(None),
+			WalletManager::from_private_key(
+				"0x0000000000000000000000000000000000000000000000000000000000000001",
+				3068,
+			)
+			.unwrap(),
+			native_metadata(),
+			mock.clone(),
+		);
+		let log = Log {
+			topics: vec![handler.socket_signature],
+			data: Bytes::from_str(SOCKET_EVENT_DATA).unwrap(),
+			block_number: Some(U64::from(100)),
+			..Default::default()
+		};
+
+		// relayer_manager.is_previous_selected_relayer() returns false
+		mock.push(Bytes::from(ethers::abi::encode(&[ethers::abi::Token::Bool(false)])))
+			.unwrap();
+		assert!(handler
+			.replay_decision(&log, None)
+			.await
+			.ends_with("skip (not selected at round 809)"));
+		// the replayed result hasn't been observed
+		assert_eq!(handler.selection_tracker.lock().unwrap().observe(U256::from(809), true), None);
+	}
+
+	#[tokio::test]
+	async fn selection_is_queried_on_the_configured_relayer_set() {
+		let mock = MockProvider::new();
+		let mut handler = socket_handler_with(
+			bootstrap_shared_data(None),
+			WalletManager::observer(3068),
+			native_metadata(),
+			mock.clone(),
+		);
+		let relayer_manager = handler.client.protocol_contracts.relayer_manager.clone().unwrap();
+		let expected_request = |is_initial: bool| {
+			let call = relayer_manager.is_previous_selected_relayer(
+				U256::from(809),
+				handler.client.address(),
+				is_initial,
+			);
+			(call.tx, BlockId::from(BlockNumber::Latest))
+		};
+		let (initial_request, current_request) = (expected_request(true), expected_request(false));
+
+		for relayer_set in [RelayerSetKind::Initial, RelayerSetKind::Current] {
+			// relayer_manager.is_previous_selected_relayer() returns false
+			mock.push(Bytes::from(ethers::abi::encode(&[ethers::abi::Token::Bool(false)])))
+				.unwrap();
+			handler.relayer_set = relayer_set;
+			assert!(!handler.query_selected_relayer(&U256::from(809)).await);
+		}
+		assert!(mock.assert_request("eth_call", initial_request).is_ok());
+		assert!(mock.assert_request("eth_call", current_request).is_ok());
+	}
+
+	#[tokio::test]
+	async fn round_stats_are_finished_on_round_transitions() {
+		let mut handler = socket_handler(bootstrap_shared_data(None));
+		handler.record_round_stats(
+			U256::from(7),
+			&SocketMessage::default(),
+			RelayOutcome::Observer,
+			false,
+		);
+
+		handler
+			.bootstrap_shared_data
+			.event_bus
+			.publish(SystemEvent::RoundAdvanced { chain_id: 3068, round: U256::from(8) });
+		handler.summarize_finished_rounds();
+
+		// round(7) has been finished without waiting for an event of round(8)
+		let mut round_stats_tracker = handler.round_stats_tracker.lock().unwrap();
+		assert_eq!(round_stats_tracker.advance(U256::from(8)), None);
+		let finished = round_stats_tracker.advance(U256::from(9)).unwrap();
+		assert_eq!((finished.round, finished.seen), (U256::from(8), 0));
+	}
+
+	#[tokio::test]
+	async fn failed_relay_requests_are_not_remembered() {
+		let mut handler = socket_handler(bootstrap_shared_data(None));
+		let socket_msg = SocketMessage::default();
+		let metadata = SocketRelayMetadata::new(
+			true,
+			SocketEventStatus::Executed,
+			0,
+			3068,
+			56,
+			Address::zero(),
+			false,
+		);
+
+		// the tx request channel has been closed
+		let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+		drop(receiver);
+		handler
+			.tx_request_senders
+			.insert(56, Arc::new(TxRequestSender::new(56, sender, false)));
+		handler
+			.request_send_transaction(
+				56,
+				TransactionRequest::default(),
+				metadata.clone(),
+				socket_msg.clone(),
+				true,
+				GasCoefficient::Low,
+			)
+			.await;
+		assert!(!handler.is_relayed(&socket_msg));
+
+		let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+		handler
+			.tx_request_senders
+			.insert(56, Arc::new(TxRequestSender::new(56, sender, false)));
+		handler
+			.request_send_transaction(
+				56,
+				TransactionRequest::default(),
+				metadata,
+				socket_msg.clone(),
+				true,
+				GasCoefficient::Low,
+			)
+			.await;
+		assert!(receiver.try_recv().is_ok());
+		assert!(handler.is_relayed(&socket_msg));
+	}
+
+	#[tokio::test]
+	async fn deferred_logs_are_bounded() {
+		let handler = socket_handler(bootstrap_shared_data(None));
+		*handler.deferred_logs.lock().unwrap() =
+			BoundedBuffer::new(1, SheddingPolicy::RejectNewest);
+
+		let log_at =
+			|number: u64| Log { block_number: Some(U64::from(number)), ..Default::default() };
+		handler.defer_log(&log_at(1), false, "the selected relayers reach the minimum");
+		handler.defer_log(&log_at(2), false, "the selected relayers reach the minimum");
+
+		let deferred_logs = handler.deferred_logs.lock().unwrap().drain();
+		assert_eq!(deferred_logs.len(), 1);
+		assert_eq!(deferred_logs[0].1 .0.block_number, Some(U64::from(1)));
+		assert_eq!(
+			br_metrics::SHED_EVENTS
+				.with_label_values(&[&handler.client.get_chain_name(), "deferred"])
+				.get(),
+			1
+		);
+	}
+
+	#[tokio::test(start_paused = true)]
+	async fn min_selected_relayers_check_is_cached_per_round() {
+		let mock = MockProvider::new();
+		let mut handler = socket_handler_with(
+			bootstrap_shared_data(None),
+			WalletManager::observer(3068),
+			native_metadata(),
+			mock.clone(),
+		);
+		handler.min_selected_relayers = Some(1);
+
+		// relayer_manager.selected_relayers() returns a single relayer only once
+		mock.push(Bytes::from(ethers::abi::encode(&[ethers::abi::Token::Array(vec![
+			ethers::abi::Token::Address(Address::zero()),
+		])])))
+		.unwrap();
+		assert!(handler.has_min_selected_relayers(U256::from(809)).await);
+		assert!(handler.has_min_selected_relayers(U256::from(809)).await);
+
+		// a new round is fetched again
+		assert!(!handler.has_min_selected_relayers(U256::from(810)).await);
+	}
+
+	#[tokio::test]
+	async fn replay_applies_the_live_gates() {
+		let handler = socket_handler_with(
+			bootstrap_shared_data(None),
+			WalletManager::observer(3068),
+			native_metadata().with_event_bounds(None, Some(10)),
+			MockProvider::new(),
+		);
+		let log = Log {
+			topics: vec![handler.socket_signature],
+			data: Bytes::from_str(SOCKET_EVENT_DATA).unwrap(),
+			block_number: Some(U64::from(100)),
+			..Default::default()
+		};
+
+		assert!(handler.replay_decision(&log, None).await.ends_with("skip (observer)"));
+		assert!(handler
+			.replay_decision(&log, Some(U64::from(110)))
+			.await
+			.ends_with("skip (observer)"));
+		assert_eq!(
+			handler.replay_decision(&log, Some(U64::from(111))).await,
+			"skip (older than the maximum event age)"
+		);
+	}
+
+	#[tokio::test]
+	async fn reverse_range_replay_goes_through_the_live_gates() {
+		let mock = MockProvider::new();
+		let handler = socket_handler_with(
+			bootstrap_shared_data(None),
+			WalletManager::observer(3068),
+			native_metadata(),
+			mock.clone(),
+		);
+		let log = |block_number: u64| Log {
+			topics: vec![handler.socket_signature],
+			data: Bytes::from_str(SOCKET_EVENT_DATA).unwrap(),
+			block_number: Some(U64::from(block_number)),
+			log_index: Some(U256::zero()),
+			..Default::default()
+		};
+
+		// the range fits in a single chunk
+		mock.push(vec![log(1), log(3), log(2)]).unwrap();
+		let summaries = handler.replay_range(U64::from(1), U64::from(5), true).await;
+
+		assert_eq!(summaries.len(), 3);
+		for (summary, block_number) in summaries.iter().zip([3, 2, 1]) {
+			assert!(summary.starts_with(&format!("#{}-", block_number)));
+			assert!(summary.ends_with("skip (observer)"));
+		}
+	}
+
+	#[tokio::test(start_paused = true)]
+	async fn does_not_busy_spin_in_transitional_states() {
+		let mut handler = socket_handler(bootstrap_shared_data(None));
+		// a chain is still syncing while the other has started its bootstrap
+		*handler.bootstrap_shared_data.bootstrap_states.write().await =
+			[(1, BootstrapState::NodeSyncing), (3068, BootstrapState::BootstrapSocketRelay)].into();
+		let interval = Duration::from_millis(handler.client.metadata.call_interval);
+
+		let started_at = tokio::time::Instant::now();
+		handler.wait_for_bootstrap_states().await;
+		assert!(started_at.elapsed() >= interval);
+
+		// the run loop keeps waiting an interval per check until the states are synced
+		assert!(tokio::time::timeout(interval * 10, handler.run()).await.is_err());
+	}
+
+	#[tokio::test]
+	async fn bootstrap_checkpoint_advances_once_relays_settle() {
+		let backend: Arc<dyn StateStore> = Arc::new(MemoryStateStore::default());
+		let store = CheckpointStore::new(backend.clone());
+		let handler = socket_handler(bootstrap_shared_data(Some(CheckpointStore::new(backend))));
+
+		// a relay requested from the first chunk is still in-flight, the scan goes on
+		let pending_guard = handler.pending_bootstrap_requests.lock().unwrap().track();
+		handler.advance_checkpoint(100);
+		handler.advance_checkpoint(200);
+		assert_eq!(store.load(3068), None);
+
+		drop(pending_guard);
+		handler.advance_checkpoint(300);
+		assert_eq!(store.load(3068), Some(300));
+
+		// deferred events haven't been relayed yet
+		handler.defer_log(&Log::default(), true, "the selected relayers reach the minimum");
+		handler.advance_checkpoint(400);
+		assert_eq!(store.load(3068), Some(300));
+		handler.deferred_logs.lock().unwrap().drain();
+
+		// a completed bootstrap keeps the checkpoint while its relays are pending
+		let pending_guard = handler.pending_bootstrap_requests.lock().unwrap().track();
+		handler.clear_checkpoint();
+		assert_eq!(store.load(3068), Some(300));
+
+		// and leaves no checkpoint behind once they have been settled
+		drop(pending_guard);
+		handler.clear_checkpoint();
+		assert_eq!(store.load(3068), None);
+	}
+
+	#[tokio::test(start_paused = true)]
+	async fn bootstrap_is_deferred_while_the_native_chain_is_unavailable() {
+		let mut bootstrap_shared_data = bootstrap_shared_data(None);
+		bootstrap_shared_data.bootstrap_config = Some(BootstrapConfig {
+			is_enabled: true,
+			round_offset: None,
+			round_drift_tolerance: None,
+			checkpoint_dir: None,
+		});
+		let handler = socket_handler(bootstrap_shared_data);
+
+		// authority.round_info() never succeeds, the bootstrap is retried on the next cycle
+		handler.bootstrap().await;
+		assert_eq!(*handler.bootstrap_shared_data.socket_bootstrap_count.lock().await, 0);
+		assert_eq!(
+			handler.bootstrap_shared_data.bootstrap_states.read().await.get(&3068),
+			Some(&BootstrapState::BootstrapSocketRelay)
+		);
+	}
+
+	#[test]
+	fn reverse_replay_processes_newest_first() {
+		assert_eq!(replay_chunks(1, 5, 2, false), vec![(1, 2), (3, 4), (5, 5)]);
+		assert_eq!(replay_chunks(1, 5, 2, true), vec![(5, 5), (3, 4), (1, 2)]);
+
+		let log = |block_number: u64, log_index: u64| Log {
+			block_number: Some(U64::from(block_number)),
+			log_index: Some(U256::from(log_index)),
+			..Default::default()
+		};
+		let order = |logs: &[Log]| -> Vec<(u64, u64)> {
+			logs.iter()
+				.map(|log| (log.block_number.unwrap().as_u64(), log.log_index.unwrap().as_u64()))
+				.collect()
+		};
+
+		let mut logs = vec![log(2, 0), log(1, 1), log(2, 1), log(1, 0)];
+		sort_logs(&mut logs, false);
+		assert_eq!(order(&logs), vec![(1, 0), (1, 1), (2, 0), (2, 1)]);
+		sort_logs(&mut logs, true);
+		assert_eq!(order(&logs), vec![(2, 1), (2, 0), (1, 1), (1, 0)]);
+	}
+
 	#[tokio::test]
 	async fn test_is_already_done() {
 		let src_provider = Arc::new(Provider::<Http>::try_from("").unwrap());
@@ -664,7 +2069,7 @@ mod tests {
 
 	#[test]
 	fn test_socket_event_decode() {
-		let data = Bytes::from_str("0x000000000000000000000000000000000000000000000000000000000000002000000bfc00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000032900000000000000000000000000000000000000000000000000000000000010fe00000000000000000000000000000000000000000000000000000000000000080000003800000000000000000000000000000000000000000000000000000000040207030100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000e0000000050000000300000bfc872b347cd764d46c127ffefbcab605fff3f3a48c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000007ac737b14b926f5fbbfb7bfa1dfcb01659da1e230000000000000000000000007ac737b14b926f5fbbfb7bfa1dfcb01659da1e2300000000000000000000000000000000000000000000000075d86ab5ce70b78000000000000000000000000000000000000000000000000000000000000000c00000000000000000000000000000000000000000000000000000000000000000").unwrap();
+		let data = Bytes::from_str(SOCKET_EVENT_DATA).unwrap();
 
 		match ethers::abi::decode(
 			&[ParamType::Tuple(vec![
@@ -1,19 +1,28 @@
-use std::{cmp::max, fmt::Debug, sync::Arc};
+use std::{
+	cmp::{max, min},
+	fmt::Debug,
+	sync::Arc,
+	time::Instant,
+};
 
 use br_primitives::{
+	abbreviate_address,
+	bootstrap::BootstrapStart,
 	constants::{
-		config::{BOOTSTRAP_BLOCK_OFFSET, NATIVE_BLOCK_TIME},
-		errors::{INSUFFICIENT_FUNDS, INVALID_CHAIN_ID, PROVIDER_INTERNAL_ERROR},
+		config::{BOOTSTRAP_BLOCK_OFFSET, MAX_RECONCILE_ATTEMPTS, NATIVE_BLOCK_TIME},
+		errors::{
+			INSUFFICIENT_FUNDS, INVALID_CHAIN_ID, PROVIDER_INTERNAL_ERROR, UNREGISTERED_RELAYER,
+		},
 		tx::{DEFAULT_CALL_RETRIES, DEFAULT_CALL_RETRY_INTERVAL_MS},
 	},
 	contracts::authority::RoundMetaData,
-	eth::{AggregatorContracts, ChainID, ProtocolContracts, ProviderMetadata},
+	eth::{AggregatorContracts, ChainID, ProtocolContracts, ProviderMetadata, RecoveredSignature},
 	sub_display_format,
 };
 use ethers::{
 	abi::Detokenize,
 	prelude::ContractCall,
-	providers::{JsonRpcClient, Middleware, Provider},
+	providers::{JsonRpcClient, Middleware, Provider, ProviderError},
 	types::{
 		Address, Block, BlockId, Filter, Log, SyncingStatus, Transaction, TransactionReceipt,
 		TxpoolContent, H256, U256, U64,
@@ -24,10 +33,12 @@ use serde::{de::DeserializeOwned, Serialize};
 use tokio::time::{sleep, Duration};
 
 use self::{
+	endpoint::{endpoint_label, is_endpoint_failure, RpcEndpoints},
 	traits::{Eip1559GasMiddleware, LegacyGasMiddleware},
 	wallet::WalletManager,
 };
 
+pub mod endpoint;
 pub mod events;
 pub mod handlers;
 pub mod traits;
@@ -48,6 +59,8 @@ pub struct EthClient<T> {
 	pub aggregator_contracts: AggregatorContracts<T>,
 	/// The ethers.rs wrapper for the connected chain.
 	provider: Arc<Provider<T>>,
+	/// The primary and fallback RPC endpoints used for calls, selected by their health.
+	endpoints: RpcEndpoints<T>,
 	/// The flag whether debug mode is enabled. If enabled, certain errors will be logged such as
 	/// gas estimation failures.
 	debug_mode: bool,
@@ -57,13 +70,21 @@ impl<T: JsonRpcClient> EthClient<T> {
 	/// Instantiates a new `EthClient` instance for the given chain.
 	pub fn new(
 		wallet: WalletManager,
-		provider: Arc<Provider<T>>,
+		endpoints: RpcEndpoints<T>,
 		metadata: ProviderMetadata,
 		protocol_contracts: ProtocolContracts<T>,
 		aggregator_contracts: AggregatorContracts<T>,
 		debug_mode: bool,
 	) -> Self {
-		Self { wallet, provider, metadata, protocol_contracts, aggregator_contracts, debug_mode }
+		Self {
+			wallet,
+			provider: endpoints.primary(),
+			endpoints,
+			metadata,
+			protocol_contracts,
+			aggregator_contracts,
+			debug_mode,
+		}
 	}
 
 	/// Returns the relayer address.
@@ -86,9 +107,66 @@ impl<T: JsonRpcClient> EthClient<T> {
 		self.provider.clone()
 	}
 
+	/// Updates the health status of the given endpoint. `latency` is `None` if the request failed.
+	fn report_endpoint_health(&self, index: usize, latency: Option<Duration>) {
+		let selector = &self.endpoints.selector;
+		let (_, _, was_degraded) = selector.status(index);
+
+		match latency {
+			Some(latency) => selector.report_success(index, latency, Instant::now()),
+			None => selector.report_failure(index, Instant::now()),
+		}
+
+		let label = endpoint_label(index);
+		let (latency_ms, consecutive_errors, is_degraded) = selector.status(index);
+		br_metrics::set_rpc_endpoint_health(
+			&self.get_chain_name(),
+			&label,
+			latency_ms,
+			consecutive_errors,
+			is_degraded,
+		);
+
+		if self.endpoints.has_fallbacks() && was_degraded != is_degraded {
+			if is_degraded {
+				log::warn!(
+					target: &self.get_chain_name(),
+					"-[{}] ⚠️  RPC endpoint degraded: {} (latency: {:.0}ms, consecutive errors: {})",
+					sub_display_format(SUB_LOG_TARGET),
+					label,
+					latency_ms,
+					consecutive_errors,
+				);
+			} else {
+				log::info!(
+					target: &self.get_chain_name(),
+					"-[{}] 💚 RPC endpoint recovered: {} (latency: {:.0}ms)",
+					sub_display_format(SUB_LOG_TARGET),
+					label,
+					latency_ms,
+				);
+			}
+		}
+	}
+
+	/// Updates the health status of the given endpoint with the result of the request sent at
+	/// `started_at`. Only transport errors and timeouts are counted as failures, errors responded
+	/// by the node (e.g. reverts) still imply a healthy endpoint.
+	fn report_endpoint_result<R>(
+		&self,
+		index: usize,
+		started_at: Instant,
+		result: &Result<R, ProviderError>,
+	) {
+		match result {
+			Err(error) if is_endpoint_failure(error) => self.report_endpoint_health(index, None),
+			_ => self.report_endpoint_health(index, Some(started_at.elapsed())),
+		}
+	}
+
 	/// Make a JSON RPC request to the chain provider via the internal connection, and return the
 	/// result. This method wraps the original JSON RPC call and retries whenever the request fails
-	/// until it exceeds the maximum retries.
+	/// until it exceeds the maximum retries. Requests are sent to the healthiest endpoint.
 	async fn rpc_call<P, R>(&self, method: &str, params: P) -> R
 	where
 		P: Debug + Serialize + Send + Sync + Clone,
@@ -99,7 +177,11 @@ impl<T: JsonRpcClient> EthClient<T> {
 
 		while retries_remaining > 0 {
 			br_metrics::increase_rpc_calls(&self.get_chain_name());
-			match self.provider.request(method, params.clone()).await {
+			let (index, provider) = self.endpoints.select();
+			let started_at = Instant::now();
+			let result = provider.request(method, params.clone()).await;
+			self.report_endpoint_result(index, started_at, &result);
+			match result {
 				Ok(result) => return result,
 				Err(error) => {
 					// retry on error
@@ -122,8 +204,29 @@ impl<T: JsonRpcClient> EthClient<T> {
 
 	/// Make a contract call to the chain provider via the internal connection, and return the
 	/// result. This method wraps the original contract call and retries whenever the request fails
-	/// until it exceeds the maximum retries.
+	/// until it exceeds the maximum retries. Calls are sent to the healthiest endpoint.
 	pub async fn contract_call<M, D>(&self, raw_call: ContractCall<M, D>, method: &str) -> D
+	where
+		M: Middleware,
+		D: Serialize + DeserializeOwned + Debug + Send + Detokenize,
+	{
+		match self.try_contract_call(raw_call).await {
+			Ok(result) => result,
+			Err(error_msg) => panic!(
+				"[{}]-[{}]-[{}] {} [method: {}]: {}",
+				&self.get_chain_name(),
+				SUB_LOG_TARGET,
+				self.address(),
+				PROVIDER_INTERNAL_ERROR,
+				method,
+				error_msg
+			),
+		}
+	}
+
+	/// Same as `contract_call`, but returns the last error instead of panicking when every retry
+	/// has failed. Used for calls whose failure can be recovered by the caller.
+	pub async fn try_contract_call<M, D>(&self, raw_call: ContractCall<M, D>) -> Result<D, String>
 	where
 		M: Middleware,
 		D: Serialize + DeserializeOwned + Debug + Send + Detokenize,
@@ -133,25 +236,29 @@ impl<T: JsonRpcClient> EthClient<T> {
 
 		while retries_remaining > 0 {
 			br_metrics::increase_rpc_calls(&self.get_chain_name());
-			match raw_call.call().await {
-				Ok(result) => return result,
+			let (index, provider) = self.endpoints.select();
+			let started_at = Instant::now();
+			let result = provider.call(&raw_call.tx, raw_call.block).await;
+			self.report_endpoint_result(index, started_at, &result);
+			let result = match result {
+				Ok(bytes) => raw_call
+					.function
+					.decode_output(bytes.as_ref())
+					.map_err(|error| error.to_string())
+					.and_then(|tokens| D::from_tokens(tokens).map_err(|error| error.to_string())),
+				Err(error) => Err(error.to_string()),
+			};
+			match result {
+				Ok(result) => return Ok(result),
 				Err(error) => {
 					// retry on error
 					retries_remaining = retries_remaining.saturating_sub(1);
-					error_msg = error.to_string();
+					error_msg = error;
 				},
 			}
 			sleep(Duration::from_millis(DEFAULT_CALL_RETRY_INTERVAL_MS)).await;
 		}
-		panic!(
-			"[{}]-[{}]-[{}] {} [method: {}]: {}",
-			&self.get_chain_name(),
-			SUB_LOG_TARGET,
-			self.address(),
-			PROVIDER_INTERNAL_ERROR,
-			method,
-			error_msg
-		);
+		Err(error_msg)
 	}
 
 	/// Verifies whether the configured chain ID and the provider's actual chain ID matches.
@@ -184,16 +291,163 @@ impl<T: JsonRpcClient> EthClient<T> {
 		}
 	}
 
+	/// Verifies whether the relayer address derived from the local key has been registered on-chain.
+	/// Otherwise the relayer would never be selected, so the process aborts.
+	pub async fn verify_relayer_registration(&self) {
+		if !self.metadata.is_native || self.wallet.is_observer() {
+			return;
+		}
+		if let Some(relayer_manager) = &self.protocol_contracts.relayer_manager {
+			let (relayers, controllers) = self
+				.contract_call(relayer_manager.relayer_pool(), "relayer_manager.relayer_pool")
+				.await;
+			if let Err(error) = verify_registration(self.address(), &relayers, &controllers) {
+				panic!(
+					"[{}]-[{}]-[{}] {} {}",
+					&self.get_chain_name(),
+					SUB_LOG_TARGET,
+					self.address(),
+					UNREGISTERED_RELAYER,
+					error
+				);
+			}
+		}
+	}
+
 	/// Retrieves the balance of the given address.
 	pub async fn get_balance(&self, who: Address) -> U256 {
 		self.rpc_call("eth_getBalance", (who, "latest")).await
 	}
 
+	/// Returns `true` and warns if a critical value returned by the given contract method is
+	/// zeroed. This implies that the contract isn't deployed at the configured address, so the
+	/// dependent action should be skipped rather than proceeding with nonsensical values.
+	pub fn is_zeroed_value(&self, is_zeroed: bool, method: &str) -> bool {
+		if is_zeroed {
+			log::warn!(
+				target: &self.get_chain_name(),
+				"-[{}] ⚠️  {} returned a zeroed value. Please check the contract address. The dependent action will be skipped.",
+				sub_display_format(SUB_LOG_TARGET),
+				method,
+			);
+		}
+		is_zeroed
+	}
+
+	/// Alerts on the given signatures whose signer isn't trusted. A signature from an untrusted
+	/// signer implies a rogue relayer in the signing set.
+	pub fn flag_untrusted_signatures(&self, signatures: &[RecoveredSignature], method: &str) {
+		let untrusted_signers = self.metadata.untrusted_signers(signatures);
+		if untrusted_signers.is_empty() {
+			return;
+		}
+
+		let msg = format!(
+			"Found signatures from untrusted signers on {}: {:?}",
+			method, untrusted_signers
+		);
+		log::error!(
+			target: &self.get_chain_name(),
+			"-[{}] 🚨 {}",
+			sub_display_format(SUB_LOG_TARGET),
+			msg,
+		);
+		sentry::capture_message(
+			format!(
+				"[{}]-[{}]-[{}] 🚨 {}",
+				&self.get_chain_name(),
+				SUB_LOG_TARGET,
+				abbreviate_address(&self.address()),
+				msg,
+			)
+			.as_str(),
+			sentry::Level::Error,
+		);
+	}
+
+	/// Requests the current gas price to the healthiest endpoint.
+	async fn request_gas_price(&self) -> Result<U256, ProviderError> {
+		let (index, provider) = self.endpoints.select();
+		let started_at = Instant::now();
+		let result = provider.get_gas_price().await;
+		self.report_endpoint_result(index, started_at, &result);
+		result
+	}
+
+	/// Requests the estimated EIP-1559 fees to the healthiest endpoint.
+	async fn request_eip1559_fees(&self) -> Result<(U256, U256), ProviderError> {
+		let (index, provider) = self.endpoints.select();
+		let started_at = Instant::now();
+		let result = provider.estimate_eip1559_fees(None).await;
+		self.report_endpoint_result(index, started_at, &result);
+		result
+	}
+
+	/// Returns `true` if the connected chain is reachable through any healthy RPC endpoint.
+	pub fn is_reachable(&self) -> bool {
+		self.endpoints.selector.is_any_healthy()
+	}
+
 	/// Retrieves the latest mined block number of the connected chain.
 	pub async fn get_latest_block_number(&self) -> U64 {
 		self.rpc_call("eth_blockNumber", ()).await
 	}
 
+	/// Retrieves the latest block number, making sure that the given imported block isn't far
+	/// ahead of it. A large forward gap implies that the local view of the chain is desynced, so
+	/// the latest block will be refreshed until the gap closes. Returns `None` if the gap didn't
+	/// close within the maximum number of refreshes.
+	pub async fn get_reconciled_block_number(&self, block_number: U64) -> Option<U64> {
+		let mut latest_block = self.get_latest_block_number().await;
+		if !self.metadata.is_far_ahead(block_number, latest_block) {
+			return Some(latest_block);
+		}
+
+		log::warn!(
+			target: &self.get_chain_name(),
+			"-[{}] ⚠️  Imported #{:?} is far ahead of the latest block #{:?}. The local view may be desynced, refreshing the latest block.",
+			sub_display_format(SUB_LOG_TARGET),
+			block_number,
+			latest_block,
+		);
+		for _ in 0..MAX_RECONCILE_ATTEMPTS {
+			sleep(Duration::from_millis(self.metadata.call_interval)).await;
+			latest_block = self.get_latest_block_number().await;
+			if !self.metadata.is_far_ahead(block_number, latest_block) {
+				log::info!(
+					target: &self.get_chain_name(),
+					"-[{}] 💚 Reconciled the latest block: #{:?}",
+					sub_display_format(SUB_LOG_TARGET),
+					latest_block,
+				);
+				return Some(latest_block);
+			}
+		}
+
+		let msg = format!(
+			"Imported #{:?} is still far ahead of the latest block #{:?} after {} refreshes",
+			block_number, latest_block, MAX_RECONCILE_ATTEMPTS,
+		);
+		log::error!(
+			target: &self.get_chain_name(),
+			"-[{}] ❗️ {}",
+			sub_display_format(SUB_LOG_TARGET),
+			msg,
+		);
+		sentry::capture_message(
+			format!(
+				"[{}]-[{}]-[{}] ❗️ {}",
+				&self.get_chain_name(),
+				SUB_LOG_TARGET,
+				abbreviate_address(&self.address()),
+				msg,
+			)
+			.as_str(),
+			sentry::Level::Error,
+		);
+		None
+	}
+
 	/// Retrieves the block information of the given block hash.
 	pub async fn get_block_with_txs(&self, id: BlockId) -> Option<Block<Transaction>> {
 		self.rpc_call("eth_getBlockByNumber", (id, true)).await
@@ -214,6 +468,11 @@ impl<T: JsonRpcClient> EthClient<T> {
 		self.rpc_call("eth_getTransactionReceipt", vec![hash]).await
 	}
 
+	/// Returns the number of transactions the given address has sent, which have been confirmed.
+	pub async fn get_transaction_count(&self, who: Address) -> U256 {
+		self.rpc_call("eth_getTransactionCount", (who, "latest")).await
+	}
+
 	/// Returns the details of all transactions currently pending for inclusion in the next
 	/// block(s).
 	pub async fn get_txpool_content(&self) -> TxpoolContent {
@@ -238,14 +497,19 @@ impl<T: JsonRpcClient> EthClient<T> {
 		round_info: RoundMetaData,
 	) -> U64 {
 		let block_number = self.get_latest_block_number().await;
+		// chains shorter than the sampling offset are sampled over their whole history.
+		let sampled_blocks = min(block_number, U64::from(BOOTSTRAP_BLOCK_OFFSET));
+		if sampled_blocks.is_zero() {
+			return block_number;
+		}
 
 		if let (Some(current_block), Some(prev_block)) = (
 			self.get_block(block_number.into()).await,
-			self.get_block((block_number - BOOTSTRAP_BLOCK_OFFSET).into()).await,
+			self.get_block((block_number - sampled_blocks).into()).await,
 		) {
 			let timestamp_diff =
 				current_block.timestamp.checked_sub(prev_block.timestamp).unwrap().as_u64() as f64;
-			let block_time = timestamp_diff / BOOTSTRAP_BLOCK_OFFSET as f64;
+			let block_time = timestamp_diff / sampled_blocks.as_u64() as f64;
 
 			let blocks = round_offset.checked_mul(round_info.round_length.as_u32()).unwrap();
 			let blocks_to_native_chain_time = blocks.checked_mul(NATIVE_BLOCK_TIME).unwrap();
@@ -262,6 +526,29 @@ impl<T: JsonRpcClient> EthClient<T> {
 		}
 	}
 
+	/// Returns the block range to bootstrap, which spans `offset_height` blocks up to the latest
+	/// block and never starts before `floor`. If the offset exceeds the available chain history,
+	/// the whole history is bootstrapped instead.
+	pub async fn get_bootstrap_range(&self, offset_height: U64, floor: U64) -> (U64, U64) {
+		let latest_block_number = self.get_latest_block_number().await;
+		let start = BootstrapStart::new(
+			latest_block_number.as_u64(),
+			offset_height.as_u64(),
+			floor.as_u64(),
+		);
+		if let BootstrapStart::ChainHistory(from_block) = start {
+			log::info!(
+				target: &self.get_chain_name(),
+				"-[{}] 📜 Bootstrap offset ({} blocks) exceeds the chain history. Bootstrapping from #{:?} to the latest block #{:?}.",
+				sub_display_format(SUB_LOG_TARGET),
+				offset_height,
+				from_block,
+				latest_block_number,
+			);
+		}
+		(U64::from(start.block()), latest_block_number)
+	}
+
 	/// Send prometheus metric of the current balance.
 	pub async fn sync_balance(&self) {
 		br_metrics::set_native_balance(
@@ -277,7 +564,7 @@ impl<T: JsonRpcClient> EthClient<T> {
 #[async_trait::async_trait]
 impl<T: JsonRpcClient> LegacyGasMiddleware for EthClient<T> {
 	async fn get_gas_price(&self) -> U256 {
-		match self.provider.get_gas_price().await {
+		match self.request_gas_price().await {
 			Ok(gas_price) => {
 				br_metrics::increase_rpc_calls(&self.get_chain_name());
 				gas_price
@@ -344,7 +631,7 @@ impl<T: JsonRpcClient> LegacyGasMiddleware for EthClient<T> {
 				);
 			}
 
-			match self.provider.get_gas_price().await {
+			match self.request_gas_price().await {
 				Ok(gas_price) => return gas_price,
 				Err(error) => {
 					sleep(Duration::from_millis(DEFAULT_CALL_RETRY_INTERVAL_MS)).await;
@@ -368,7 +655,7 @@ impl<T: JsonRpcClient> LegacyGasMiddleware for EthClient<T> {
 #[async_trait::async_trait]
 impl<T: JsonRpcClient> Eip1559GasMiddleware for EthClient<T> {
 	async fn get_estimated_eip1559_fees(&self) -> (U256, U256) {
-		match self.provider.estimate_eip1559_fees(None).await {
+		match self.request_eip1559_fees().await {
 			Ok(fees) => {
 				br_metrics::increase_rpc_calls(&self.get_chain_name());
 				fees
@@ -416,7 +703,7 @@ impl<T: JsonRpcClient> Eip1559GasMiddleware for EthClient<T> {
 				);
 			}
 
-			match self.provider.estimate_eip1559_fees(None).await {
+			match self.request_eip1559_fees().await {
 				Ok(fees) => return fees,
 				Err(error) => {
 					sleep(Duration::from_millis(DEFAULT_CALL_RETRY_INTERVAL_MS)).await;
@@ -436,3 +723,121 @@ impl<T: JsonRpcClient> Eip1559GasMiddleware for EthClient<T> {
 		);
 	}
 }
+
+/// Verifies whether the given address is one of the registered relayers. A controller address is
+/// rejected as well, since only the relayer address is checked for selection.
+fn verify_registration(
+	address: Address,
+	relayers: &[Address],
+	controllers: &[Address],
+) -> Result<(), String> {
+	if relayers.contains(&address) {
+		return Ok(());
+	}
+	if controllers.contains(&address) {
+		return Err(format!("({:?} is registered as a controller, not as a relayer)", address));
+	}
+	Err(format!("({:?} is not in the relayer pool)", address))
+}
+
+/// Builds a client with the given wallet and metadata, whose provider responds with the responses
+/// pushed to the given mock. Every contract is deployed at the zero address.
+#[cfg(test)]
+pub(crate) fn mock_client(
+	wallet: WalletManager,
+	metadata: ProviderMetadata,
+	mock: ethers::providers::MockProvider,
+) -> EthClient<ethers::providers::MockProvider> {
+	let provider = Arc::new(Provider::new(mock));
+	let zero_address = String::from("0x0000000000000000000000000000000000000000");
+	EthClient::new(
+		wallet,
+		RpcEndpoints::new(
+			provider.clone(),
+			vec![],
+			endpoint::HealthThresholds {
+				latency: Duration::from_secs(3),
+				consecutive_errors: 3,
+				recovery_interval: Duration::from_secs(60),
+			},
+		),
+		metadata,
+		ProtocolContracts::new(
+			provider.clone(),
+			zero_address.clone(),
+			zero_address.clone(),
+			Some(zero_address),
+		),
+		AggregatorContracts::new(provider, None, None, None, None, None),
+		false,
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use ethers::providers::MockProvider;
+
+	use super::*;
+
+	#[tokio::test(start_paused = true)]
+	async fn contract_call_to_unavailable_client_can_be_recovered() {
+		// the provider never responds
+		let client = mock_client(
+			WalletManager::from_private_key(
+				"0x0000000000000000000000000000000000000000000000000000000000000001",
+				3068,
+			)
+			.unwrap(),
+			ProviderMetadata::new(String::from("bifrost"), 3068, 0, 3000, 1, true),
+			MockProvider::new(),
+		);
+
+		let result: Result<RoundMetaData, String> =
+			client.try_contract_call(client.protocol_contracts.authority.round_info()).await;
+		assert!(result.is_err());
+	}
+
+	#[tokio::test(start_paused = true)]
+	async fn large_forward_gap_is_reconciled_within_bound() {
+		let mock = MockProvider::new();
+		// imported blocks are accepted at most 10 blocks ahead of the latest block
+		let client = mock_client(
+			WalletManager::observer(3068),
+			ProviderMetadata::new(String::from("bifrost"), 3068, 0, 3000, 1, true)
+				.with_max_forward_gap(10),
+			mock.clone(),
+		);
+
+		mock.push(U64::from(1_000)).unwrap();
+		assert_eq!(client.get_reconciled_block_number(U64::from(1_010)).await, Some(1_000.into()));
+
+		// the local view never catches up with the imported block
+		for _ in 0..=MAX_RECONCILE_ATTEMPTS {
+			mock.push(U64::from(1_000)).unwrap();
+		}
+		assert_eq!(client.get_reconciled_block_number(U64::from(50_000)).await, None);
+	}
+
+	#[test]
+	fn mismatched_key_is_not_registered() {
+		let registered = WalletManager::from_private_key(
+			"0x0000000000000000000000000000000000000000000000000000000000000001",
+			3068,
+		)
+		.unwrap()
+		.address();
+		let mismatched = WalletManager::from_private_key(
+			"0x0000000000000000000000000000000000000000000000000000000000000002",
+			3068,
+		)
+		.unwrap()
+		.address();
+		let controller = Address::repeat_byte(1);
+
+		assert!(verify_registration(registered, &[registered], &[controller]).is_ok());
+		assert!(verify_registration(mismatched, &[registered], &[controller]).is_err());
+		assert!(verify_registration(controller, &[registered], &[controller])
+			.unwrap_err()
+			.contains("controller"));
+	}
+}
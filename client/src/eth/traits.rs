@@ -4,7 +4,9 @@ use br_primitives::{
 	contracts::socket::{PollSubmit, Signatures, SocketMessage},
 	eth::{BootstrapState, BuiltRelayTransaction, ChainID, GasCoefficient, RecoveredSignature},
 	sub_display_format,
-	tx::{FlushMetadata, TxRequest, TxRequestMessage, TxRequestMetadata},
+	tx::{
+		FlushMetadata, MissingReceipt, TxRejection, TxRequest, TxRequestMessage, TxRequestMetadata,
+	},
 };
 use ethers::{
 	abi::Token,
@@ -143,6 +145,8 @@ pub trait SocketRelayBuilder<T> {
 				self.get_client().wallet.recover_message(sig, &encoded_msg),
 			));
 		}
+		self.get_client()
+			.flag_untrusted_signatures(&recovered_sigs, "socket.get_signatures");
 		recovered_sigs.sort_by_key(|k| k.signer);
 
 		let mut sorted_sigs = Signatures::default();
@@ -429,33 +433,56 @@ where
 		self.retry_transaction(msg, escalation).await;
 	}
 
-	/// Handles the failed transaction receipt generation.
-	async fn handle_failed_tx_receipt(&self, sub_target: &str, msg: TxRequestMessage) {
+	/// Handles the sent transaction that returned no receipt. Some providers don't return the
+	/// receipt of a mined transaction, so this is not treated as a hard failure. If re-querying
+	/// is enabled, the request won't be retried once the nonce of the transaction has been
+	/// consumed.
+	async fn handle_missing_tx_receipt(
+		&self,
+		sub_target: &str,
+		msg: TxRequestMessage,
+		pending: TxHash,
+	) {
 		let client = self.get_client();
 
-		log::error!(
+		let resolution = if client.metadata.requery_missing_receipt {
+			let receipt = client.get_transaction_receipt(pending).await;
+			br_metrics::increase_rpc_calls(&client.get_chain_name());
+			if let Some(receipt) = receipt {
+				return self.handle_success_tx_receipt(sub_target, receipt, msg.metadata);
+			}
+
+			let sent_nonce = client.get_transaction(pending).await.map(|tx| tx.nonce);
+			br_metrics::increase_rpc_calls(&client.get_chain_name());
+			let confirmed_nonce = client.get_transaction_count(client.address()).await;
+			br_metrics::increase_rpc_calls(&client.get_chain_name());
+			MissingReceipt::resolve(sent_nonce, confirmed_nonce)
+		} else {
+			MissingReceipt::Unresolved
+		};
+		br_metrics::increase_missing_receipts(&client.get_chain_name(), resolution.as_str());
+
+		if resolution == MissingReceipt::NonceConsumed {
+			log::warn!(
+				target: &client.get_chain_name(),
+				"-[{}] ⚠️  The requested transaction returned no receipt, but its nonce has been consumed. Skipped retrying: {}-{}",
+				sub_display_format(sub_target),
+				msg.metadata,
+				pending,
+			);
+			return;
+		}
+		log::warn!(
 			target: &client.get_chain_name(),
-			"-[{}] ♻️  The requested transaction failed to generate a receipt: {}, Retries left: {:?}",
+			"-[{}] ♻️  The requested transaction returned no receipt: {}-{}, Retries left: {:?}",
 			sub_display_format(sub_target),
 			msg.metadata,
+			pending,
 			msg.retries_remaining - 1,
 		);
-		sentry::capture_message(
-            format!(
-                "[{}]-[{}]-[{}] ♻️  The requested transaction failed to generate a receipt: {}, Retries left: {:?}",
-                &client.get_chain_name(),
-                sub_target,
-                client.address(),
-                msg.metadata,
-                msg.retries_remaining - 1,
-            )
-                .as_str(),
-            sentry::Level::Error,
-        );
 		self.retry_transaction(msg, false).await;
 	}
 
-	/// Handles the failed transaction request.
 	async fn handle_failed_tx_request<E: Error + Sync + ?Sized>(
 		&self,
 		sub_target: &str,
@@ -464,6 +491,9 @@ where
 	) {
 		let client = self.get_client();
 
+		if self.is_conflicting_tx(sub_target, &msg.metadata, error) {
+			return;
+		}
 		log::error!(
 			target: &client.get_chain_name(),
 			"-[{}] ♻️  Unknown error while requesting a transaction request: {}, Retries left: {:?}, Error: {}",
@@ -488,6 +518,32 @@ where
 		self.retry_transaction(msg, false).await;
 	}
 
+	/// Verifies whether the given rejection was caused by another relayer's submission that has
+	/// already changed the contract state. A conflicting request is marked as done by its
+	/// competitor, so it's only logged and won't be retried.
+	fn is_conflicting_tx<E: Error + Sync + ?Sized>(
+		&self,
+		sub_target: &str,
+		metadata: &TxRequestMetadata,
+		error: &E,
+	) -> bool {
+		let client = self.get_client();
+
+		match TxRejection::classify(&error.to_string(), &client.metadata.conflict_revert_reasons) {
+			TxRejection::Conflict => {
+				log::info!(
+					target: &client.get_chain_name(),
+					"-[{}] 🤝 The transaction request has already been processed by another relayer: {}, Reason: {}",
+					sub_display_format(sub_target),
+					metadata,
+					error.to_string(),
+				);
+				true
+			},
+			TxRejection::Failure => false,
+		}
+	}
+
 	/// Handles the failed gas estimation.
 	async fn handle_failed_gas_estimation<E: Error + Sync + ?Sized>(
 		&self,
@@ -497,6 +553,9 @@ where
 	) {
 		let client = self.get_client();
 
+		if self.is_conflicting_tx(sub_target, &msg.metadata, error) {
+			return;
+		}
 		if self.debug_mode() {
 			log::warn!(
 				target: &client.get_chain_name(),
@@ -1,4 +1,5 @@
 use crate::eth::{
+	endpoint::FailoverMiddleware,
 	traits::{TransactionManager, TransactionTask},
 	Eip1559GasMiddleware, EthClient,
 };
@@ -6,6 +7,7 @@ use crate::eth::{
 use async_trait::async_trait;
 use br_primitives::{
 	constants::{
+		cli::DEFAULT_MAX_RANDOM_DELAY_MS,
 		config::ETHEREUM_BLOCK_TIME,
 		errors::{INSUFFICIENT_FUNDS, NETWORK_DOES_NOT_SUPPORT_EIP1559, PROVIDER_INTERNAL_ERROR},
 		tx::{DEFAULT_TX_RETRIES, MAX_FEE_COEFFICIENT, MAX_PRIORITY_FEE_COEFFICIENT},
@@ -51,6 +53,9 @@ pub struct Eip1559TransactionManager<T> {
 	/// If first relay transaction is stuck in mempool after waiting for this amount of time(ms),
 	/// ignore duplicate prevent logic. (default: 12s)
 	duplicate_confirm_delay: Option<u64>,
+	/// The maximum random delay(ms) given before the first try of external chain relay
+	/// transactions. (default: 12s)
+	max_random_delay: u64,
 	/// A handle for spawning transaction tasks in the service.
 	tx_spawn_handle: SpawnTaskHandle,
 }
@@ -61,13 +66,13 @@ impl<T: 'static + JsonRpcClient> Eip1559TransactionManager<T> {
 		client: Arc<EthClient<T>>,
 		min_priority_fee: U256,
 		duplicate_confirm_delay: Option<u64>,
+		max_random_delay: Option<u64>,
 		tx_spawn_handle: SpawnTaskHandle,
 	) -> (Self, UnboundedSender<TxRequestMessage>) {
 		let (sender, receiver) = mpsc::unbounded_channel::<TxRequestMessage>();
 
 		let middleware = Arc::new(
-			client
-				.get_provider()
+			FailoverMiddleware::new(client.clone())
 				.wrap_into(|p| SignerMiddleware::new(p, client.wallet.signer.clone()))
 				.wrap_into(|p| NonceManagerMiddleware::new(p, client.address())),
 		);
@@ -80,6 +85,7 @@ impl<T: 'static + JsonRpcClient> Eip1559TransactionManager<T> {
 				is_txpool_enabled: false,
 				min_priority_fee,
 				duplicate_confirm_delay,
+				max_random_delay: max_random_delay.unwrap_or(DEFAULT_MAX_RANDOM_DELAY_MS),
 				tx_spawn_handle,
 			},
 			sender,
@@ -125,12 +131,17 @@ impl<T: 'static + JsonRpcClient> TransactionManager<T> for Eip1559TransactionMan
 			self.is_txpool_enabled(),
 			self.min_priority_fee,
 			self.duplicate_confirm_delay,
+			self.max_random_delay,
 		);
+		let chain_name = self.client.get_chain_name();
+		br_metrics::increase_backlog_depth(&chain_name, "submission");
 		if msg.is_bootstrap {
 			task.try_send_transaction(msg).await;
+			br_metrics::decrease_backlog_depth(&chain_name, "submission");
 		} else {
 			self.get_spawn_handle().spawn("send_transaction", None, async move {
-				task.try_send_transaction(msg).await
+				task.try_send_transaction(msg).await;
+				br_metrics::decrease_backlog_depth(&chain_name, "submission");
 			});
 		}
 	}
@@ -213,6 +224,9 @@ pub struct Eip1559TransactionTask<T> {
 	/// If first relay transaction is stuck in mempool after waiting for this amount of time(ms),
 	/// ignore duplicate prevent logic. (default: 12s)
 	duplicate_confirm_delay: Option<u64>,
+	/// The maximum random delay(ms) given before the first try of external chain relay
+	/// transactions. (default: 12s)
+	max_random_delay: u64,
 }
 
 impl<T: JsonRpcClient> Eip1559TransactionTask<T> {
@@ -223,8 +237,16 @@ impl<T: JsonRpcClient> Eip1559TransactionTask<T> {
 		is_txpool_enabled: bool,
 		min_priority_fee: U256,
 		duplicate_confirm_delay: Option<u64>,
+		max_random_delay: u64,
 	) -> Self {
-		Self { client, middleware, is_txpool_enabled, min_priority_fee, duplicate_confirm_delay }
+		Self {
+			client,
+			middleware,
+			is_txpool_enabled,
+			min_priority_fee,
+			duplicate_confirm_delay,
+			max_random_delay,
+		}
 	}
 }
 
@@ -252,10 +274,19 @@ impl<T: JsonRpcClient> TransactionTask<T> for Eip1559TransactionTask<T> {
 		if msg.retries_remaining == 0 {
 			return;
 		}
-
-		// sets a random delay on external chain transactions on first try
-		if msg.give_random_delay && msg.retries_remaining == DEFAULT_TX_RETRIES {
-			sleep(Duration::from_millis(generate_delay())).await;
+		// never fall back to the ephemeral observer key
+		self.client.wallet.assert_signable();
+
+		if msg.retries_remaining == DEFAULT_TX_RETRIES {
+			// staggers the first try among co-located relayers by their signing priority
+			let mut delay = self.client.wallet.signing_stagger();
+			// sets a random delay on external chain transactions on first try
+			if msg.give_random_delay {
+				delay += Duration::from_millis(generate_delay(self.max_random_delay));
+			}
+			if !delay.is_zero() {
+				sleep(delay).await;
+			}
 		}
 
 		// set transaction `from` field
@@ -302,17 +333,25 @@ impl<T: JsonRpcClient> TransactionTask<T> for Eip1559TransactionTask<T> {
 			br_metrics::increase_rpc_calls(&self.client.get_chain_name());
 
 			match result {
-				Ok(pending_tx) => match pending_tx.await {
-					Ok(receipt) => {
-						if let Some(receipt) = receipt {
-							self.handle_success_tx_receipt(SUB_LOG_TARGET, receipt, msg.metadata);
-						} else {
-							self.handle_failed_tx_receipt(SUB_LOG_TARGET, msg).await;
-						}
-					},
-					Err(error) => {
-						self.handle_failed_tx_request(SUB_LOG_TARGET, msg, &error).await;
-					},
+				Ok(pending_tx) => {
+					let pending_hash = pending_tx.tx_hash();
+					match pending_tx.await {
+						Ok(receipt) => {
+							if let Some(receipt) = receipt {
+								self.handle_success_tx_receipt(
+									SUB_LOG_TARGET,
+									receipt,
+									msg.metadata,
+								);
+							} else {
+								self.handle_missing_tx_receipt(SUB_LOG_TARGET, msg, pending_hash)
+									.await;
+							}
+						},
+						Err(error) => {
+							self.handle_failed_tx_request(SUB_LOG_TARGET, msg, &error).await;
+						},
+					}
 				},
 				Err(error) => {
 					self.handle_failed_tx_request(SUB_LOG_TARGET, msg, &error).await;
@@ -1,4 +1,5 @@
 use crate::eth::{
+	endpoint::FailoverMiddleware,
 	traits::{TransactionManager, TransactionTask},
 	EthClient, LegacyGasMiddleware,
 };
@@ -6,7 +7,7 @@ use crate::eth::{
 use async_trait::async_trait;
 use br_primitives::{
 	constants::{
-		cli::{DEFAULT_ESCALATE_PERCENTAGE, DEFAULT_MIN_GAS_PRICE},
+		cli::{DEFAULT_ESCALATE_PERCENTAGE, DEFAULT_MAX_RANDOM_DELAY_MS, DEFAULT_MIN_GAS_PRICE},
 		config::ETHEREUM_BLOCK_TIME,
 		errors::{INSUFFICIENT_FUNDS, NETWORK_DOES_NOT_SUPPORT_EIP1559, PROVIDER_INTERNAL_ERROR},
 		tx::DEFAULT_TX_RETRIES,
@@ -54,6 +55,9 @@ pub struct LegacyTransactionManager<T> {
 	/// If first relay transaction is stuck in mempool after waiting for this amount of time(ms),
 	/// ignore duplicate prevent logic. (default: 12s)
 	duplicate_confirm_delay: Option<u64>,
+	/// The maximum random delay(ms) given before the first try of external chain relay
+	/// transactions. (default: 12s)
+	max_random_delay: u64,
 	/// A handle for spawning transaction tasks in the service.
 	tx_spawn_handle: SpawnTaskHandle,
 }
@@ -66,6 +70,7 @@ impl<T: 'static + JsonRpcClient> LegacyTransactionManager<T> {
 		min_gas_price: Option<u64>,
 		is_initially_escalated: bool,
 		duplicate_confirm_delay: Option<u64>,
+		max_random_delay: Option<u64>,
 		tx_spawn_handle: SpawnTaskHandle,
 	) -> (Self, UnboundedSender<TxRequestMessage>) {
 		let (sender, receiver) = mpsc::unbounded_channel::<TxRequestMessage>();
@@ -78,8 +83,7 @@ impl<T: 'static + JsonRpcClient> LegacyTransactionManager<T> {
 			}
 		};
 
-		let middleware = client
-			.get_provider()
+		let middleware = FailoverMiddleware::new(client.clone())
 			.wrap_into(|p| SignerMiddleware::new(p, client.wallet.signer.clone()))
 			.wrap_into(|p| NonceManagerMiddleware::new(p, client.address()));
 
@@ -93,6 +97,7 @@ impl<T: 'static + JsonRpcClient> LegacyTransactionManager<T> {
 				gas_price_coefficient,
 				min_gas_price: U256::from(min_gas_price.unwrap_or(DEFAULT_MIN_GAS_PRICE)),
 				duplicate_confirm_delay,
+				max_random_delay: max_random_delay.unwrap_or(DEFAULT_MAX_RANDOM_DELAY_MS),
 				tx_spawn_handle,
 			},
 			sender,
@@ -140,12 +145,17 @@ impl<T: 'static + JsonRpcClient> TransactionManager<T> for LegacyTransactionMana
 			self.gas_price_coefficient,
 			self.min_gas_price,
 			self.duplicate_confirm_delay,
+			self.max_random_delay,
 		);
+		let chain_name = self.client.get_chain_name();
+		br_metrics::increase_backlog_depth(&chain_name, "submission");
 		if msg.is_bootstrap {
 			task.try_send_transaction(msg).await;
+			br_metrics::decrease_backlog_depth(&chain_name, "submission");
 		} else {
 			self.get_spawn_handle().spawn("send_transaction", None, async move {
-				task.try_send_transaction(msg).await
+				task.try_send_transaction(msg).await;
+				br_metrics::decrease_backlog_depth(&chain_name, "submission");
 			});
 		}
 	}
@@ -224,6 +234,9 @@ pub struct LegacyTransactionTask<T> {
 	/// If first relay transaction is stuck in mempool after waiting for this amount of time(ms),
 	/// ignore duplicate prevent logic. (default: 12s)
 	duplicate_confirm_delay: Option<u64>,
+	/// The maximum random delay(ms) given before the first try of external chain relay
+	/// transactions. (default: 12s)
+	max_random_delay: u64,
 }
 
 impl<T: JsonRpcClient> LegacyTransactionTask<T> {
@@ -236,6 +249,7 @@ impl<T: JsonRpcClient> LegacyTransactionTask<T> {
 		gas_price_coefficient: f64,
 		min_gas_price: U256,
 		duplicate_confirm_delay: Option<u64>,
+		max_random_delay: u64,
 	) -> Self {
 		Self {
 			client,
@@ -245,6 +259,7 @@ impl<T: JsonRpcClient> LegacyTransactionTask<T> {
 			gas_price_coefficient,
 			min_gas_price,
 			duplicate_confirm_delay,
+			max_random_delay,
 		}
 	}
 }
@@ -271,10 +286,19 @@ impl<T: JsonRpcClient> TransactionTask<T> for LegacyTransactionTask<T> {
 		if msg.retries_remaining == 0 {
 			return;
 		}
-
-		// sets a random delay on external chain transactions on first try
-		if msg.give_random_delay && msg.retries_remaining == DEFAULT_TX_RETRIES {
-			sleep(Duration::from_millis(generate_delay())).await;
+		// never fall back to the ephemeral observer key
+		self.client.wallet.assert_signable();
+
+		if msg.retries_remaining == DEFAULT_TX_RETRIES {
+			// staggers the first try among co-located relayers by their signing priority
+			let mut delay = self.client.wallet.signing_stagger();
+			// sets a random delay on external chain transactions on first try
+			if msg.give_random_delay {
+				delay += Duration::from_millis(generate_delay(self.max_random_delay));
+			}
+			if !delay.is_zero() {
+				sleep(delay).await;
+			}
 		}
 
 		// set transaction `from` field
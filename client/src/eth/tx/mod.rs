@@ -1,10 +1,10 @@
 use ethers::{
 	prelude::{NonceManagerMiddleware, SignerMiddleware},
-	providers::Provider,
 	signers::LocalWallet,
 };
 use rand::Rng;
-use std::sync::Arc;
+
+use crate::eth::endpoint::FailoverMiddleware;
 
 mod eip1559_manager;
 mod legacy_manager;
@@ -12,11 +12,107 @@ mod legacy_manager;
 pub use eip1559_manager::*;
 pub use legacy_manager::*;
 
-/// The tranaction middleware type used for `TransactionManager`'s.
+/// The tranaction middleware type used for `TransactionManager`'s. Requests are sent through the
+/// RPC endpoint failover of the client.
 pub type TransactionMiddleware<T> =
-	NonceManagerMiddleware<SignerMiddleware<Arc<Provider<T>>, LocalWallet>>;
+	NonceManagerMiddleware<SignerMiddleware<FailoverMiddleware<T>, LocalWallet>>;
+
+/// Generates a random delay that is ranged as 0 to `max_delay` milliseconds (in milliseconds).
+pub fn generate_delay(max_delay: u64) -> u64 {
+	rand::thread_rng().gen_range(0..=max_delay)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::Arc;
+
+	use br_primitives::{
+		eth::{GasCoefficient, ProviderMetadata},
+		tx::{HeartbeatMetadata, TxRequest, TxRequestMessage, TxRequestMetadata},
+	};
+	use ethers::{
+		middleware::MiddlewareBuilder,
+		providers::MockProvider,
+		types::{TransactionRequest, U256},
+	};
+	use tokio::task::yield_now;
+
+	use super::*;
+	use crate::eth::{mock_client, traits::TransactionTask, wallet::WalletManager};
+
+	/// Builds a legacy transaction task of the native chain(3068) that waits at least one second
+	/// before the first try of its transactions.
+	fn legacy_task(mock: MockProvider) -> LegacyTransactionTask<MockProvider> {
+		let client = Arc::new(mock_client(
+			WalletManager::from_private_key(
+				"0x0000000000000000000000000000000000000000000000000000000000000001",
+				3068,
+			)
+			.unwrap()
+			.with_signing_priority(1, 1_000),
+			ProviderMetadata::new(String::from("bifrost"), 3068, 0, 3000, 1, true),
+			mock,
+		));
+		let middleware = FailoverMiddleware::new(client.clone())
+			.wrap_into(|p| SignerMiddleware::new(p, client.wallet.signer.clone()))
+			.wrap_into(|p| NonceManagerMiddleware::new(p, client.address()));
+
+		LegacyTransactionTask::new(
+			client,
+			Arc::new(middleware),
+			false,
+			false,
+			1.0,
+			U256::zero(),
+			None,
+			60_000,
+		)
+	}
+
+	fn heartbeat_request() -> TxRequestMessage {
+		TxRequestMessage::new(
+			TxRequest::Legacy(TransactionRequest::default()),
+			TxRequestMetadata::Heartbeat(HeartbeatMetadata::new(U256::one(), U256::one())),
+			false,
+			true,
+			GasCoefficient::Mid,
+			false,
+		)
+	}
+
+	#[test]
+	fn generate_delay_is_bounded() {
+		assert_eq!(generate_delay(0), 0);
+		for _ in 0..1000 {
+			assert!(generate_delay(100) <= 100);
+		}
+	}
+
+	#[tokio::test(start_paused = true)]
+	async fn transaction_delays_are_cancelable() {
+		let mock = MockProvider::new();
+		let task = Arc::new(legacy_task(mock.clone()));
+
+		// the first try waits for the signing stagger and the random delay, and a retry waits for
+		// the retry interval
+		let first_try = {
+			let task = task.clone();
+			tokio::spawn(async move { task.try_send_transaction(heartbeat_request()).await })
+		};
+		let retry = {
+			let task = task.clone();
+			tokio::spawn(async move { task.retry_transaction(heartbeat_request(), false).await })
+		};
+		for _ in 0..10 {
+			yield_now().await;
+		}
 
-/// Generates a random delay that is ranged as 0 to 12000 milliseconds (in milliseconds).
-pub fn generate_delay() -> u64 {
-	rand::thread_rng().gen_range(0..=12000)
+		for handle in [first_try, retry] {
+			assert!(!handle.is_finished());
+			handle.abort();
+			assert!(handle.await.unwrap_err().is_cancelled());
+		}
+		// nothing has been requested to the chain
+		assert!(mock.assert_request("eth_gasPrice", ()).is_err());
+	}
 }
@@ -1,6 +1,10 @@
-use br_primitives::{constants::errors::INVALID_PRIVATE_KEY, eth::ChainID};
+use br_primitives::{
+	constants::errors::{INVALID_PRIVATE_KEY, SUBMISSION_WITHOUT_PRIVATE_KEY},
+	eth::ChainID,
+};
 
 use ethers::{
+	core::rand::thread_rng,
 	prelude::k256::ecdsa::SigningKey,
 	signers::{LocalWallet, Signer},
 	types::{Address, Signature, U256},
@@ -13,6 +17,8 @@ use k256::{
 };
 use sha3::{Digest, Keccak256};
 
+use std::time::Duration;
+
 type WalletResult<T = ()> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
 
 #[derive(Debug)]
@@ -21,8 +27,11 @@ type WalletResult<T = ()> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
 pub struct WalletManager {
 	/// The wallet instantiated with a locally stored private key.
 	pub signer: ethers::signers::Wallet<SigningKey>,
-	/// The ECDSA/secp256k1 signing key.
+	/// The ECDSA/secp256k1 signing key. `None` if the relayer runs as an observer.
 	secret_key: Option<K256SigningKey>,
+	/// The delay given before the first try of transactions, determined by the signing priority
+	/// among co-located relayers.
+	signing_stagger: Duration,
 }
 
 impl WalletManager {
@@ -37,11 +46,50 @@ impl WalletManager {
 			.expect(INVALID_PRIVATE_KEY);
 		let signing_key = K256SigningKey::from_bytes(&pk_bytes.into()).expect(INVALID_PRIVATE_KEY);
 
-		Ok(Self { signer: wallet.with_chain_id(chain_id), secret_key: Some(signing_key) })
+		Ok(Self {
+			signer: wallet.with_chain_id(chain_id),
+			secret_key: Some(signing_key),
+			signing_stagger: Duration::ZERO,
+		})
+	}
+
+	/// Initialize `WalletManager` for the observer mode. No private key is required, the signer is
+	/// an ephemeral key that is never registered as a relayer and never used for submission.
+	pub fn observer(chain_id: ChainID) -> Self {
+		Self {
+			signer: LocalWallet::new(&mut thread_rng()).with_chain_id(chain_id),
+			secret_key: None,
+			signing_stagger: Duration::ZERO,
+		}
+	}
+
+	/// Sets the signing priority among co-located relayers. The identity of the given index waits
+	/// `index * stagger_interval` milliseconds before the first try of its transactions.
+	pub fn with_signing_priority(mut self, index: u32, stagger_interval: u64) -> Self {
+		self.signing_stagger = Duration::from_millis(stagger_interval.saturating_mul(index as u64));
+		self
+	}
+
+	/// Returns the delay given before the first try of transactions.
+	pub fn signing_stagger(&self) -> Duration {
+		self.signing_stagger
+	}
+
+	/// Returns `true` if the relayer runs as a read-only observer.
+	pub fn is_observer(&self) -> bool {
+		self.secret_key.is_none()
+	}
+
+	/// Refuses to go further if no private key has been configured. Signing or submitting with
+	/// the ephemeral observer key is always a bug, so this is a hard error.
+	pub fn assert_signable(&self) {
+		assert!(!self.is_observer(), "{}", SUBMISSION_WITHOUT_PRIVATE_KEY);
 	}
 
 	/// Signs the given message and returns the generated signature.
 	pub fn sign_message(&self, msg: &[u8]) -> Signature {
+		self.assert_signable();
+
 		let digest = Keccak256::new_with_prefix(msg);
 		let (sig, recovery_id) =
 			self.secret_key.clone().unwrap().sign_digest_recoverable(digest).unwrap();
@@ -77,3 +125,33 @@ impl WalletManager {
 		self.signer.address()
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	#[should_panic(expected = "Attempted to sign or submit without a configured private key")]
+	fn observer_refuses_to_sign() {
+		WalletManager::observer(3068).sign_message(b"poll");
+	}
+
+	#[test]
+	#[should_panic(expected = "Attempted to sign or submit without a configured private key")]
+	fn observer_refuses_to_submit() {
+		WalletManager::observer(3068).assert_signable();
+	}
+
+	#[test]
+	fn configured_key_is_signable() {
+		let wallet = WalletManager::from_private_key(
+			"0x0000000000000000000000000000000000000000000000000000000000000001",
+			3068,
+		)
+		.unwrap();
+		wallet.assert_signable();
+
+		let sig = wallet.sign_message(b"poll");
+		assert_eq!(wallet.recover_message(sig, b"poll"), wallet.address());
+	}
+}
@@ -1,8 +1,13 @@
 pub mod prometheus;
 pub use crate::prometheus::*;
 
+pub mod report;
+
 pub mod sentry;
 pub use crate::sentry::*;
 
+#[cfg(feature = "statsd")]
+pub mod statsd;
+
 #[macro_use]
 extern crate lazy_static;
@@ -3,6 +3,12 @@ use std::time::SystemTime;
 use ethers::{types::TransactionReceipt, utils::format_units};
 use prometheus_endpoint::{Gauge, GaugeVec, Opts, Registry, F64, U64};
 
+/// The stages of the backlog. Each stage is also summed up as `total`.
+/// - `channel`: block messages queued for the handlers
+/// - `confirmation`: blocks awaiting enough confirmations
+/// - `submission`: transaction requests awaiting their submission to be finished
+pub const BACKLOG_STAGES: [&str; 3] = ["channel", "confirmation", "submission"];
+
 lazy_static! {
 	pub static ref BLOCK_HEIGHT: GaugeVec<U64> = GaugeVec::<U64>::new(
 		Opts::new("relayer_block_height", "Block height of the chain"),
@@ -24,6 +30,75 @@ lazy_static! {
 		&["chain_name"],
 	)
 	.unwrap();
+	pub static ref CURRENT_ROUND: GaugeVec<U64> = GaugeVec::<U64>::new(
+		Opts::new("relayer_current_round", "Latest observed round of the chain"),
+		&["chain_name"],
+	)
+	.unwrap();
+	pub static ref SELECTION_TRANSITIONS: GaugeVec<U64> = GaugeVec::<U64>::new(
+		Opts::new(
+			"relayer_selection_transitions",
+			"Transitions of the relayer into (selected) or out of (unselected) the selected relayer set of the chain"
+		),
+		&["chain_name", "direction"],
+	)
+	.unwrap();
+	pub static ref SHED_EVENTS: GaugeVec<U64> = GaugeVec::<U64>::new(
+		Opts::new(
+			"relayer_shed_events",
+			"Events shed due to a full buffer of the chain, by buffer (rollback, dead_letter, deferred)"
+		),
+		&["chain_name", "buffer"],
+	)
+	.unwrap();
+	pub static ref MISSING_RECEIPTS: GaugeVec<U64> = GaugeVec::<U64>::new(
+		Opts::new(
+			"relayer_missing_receipts",
+			"Sent transactions of the chain that returned no receipt, by their resolution"
+		),
+		&["chain_name", "resolution"],
+	)
+	.unwrap();
+	pub static ref MALFORMED_STREAM_ITEMS: GaugeVec<U64> = GaugeVec::<U64>::new(
+		Opts::new(
+			"relayer_malformed_stream_items",
+			"Erroneous event stream items skipped by the handlers of the chain"
+		),
+		&["chain_name"],
+	)
+	.unwrap();
+	pub static ref BACKLOG_DEPTH: GaugeVec<U64> = GaugeVec::<U64>::new(
+		Opts::new(
+			"relayer_backlog_depth",
+			"Items awaiting processing or submission of the chain, by stage (channel, confirmation, submission) and in total"
+		),
+		&["chain_name", "stage"],
+	)
+	.unwrap();
+	pub static ref RPC_ENDPOINT_LATENCY: GaugeVec<F64> = GaugeVec::<F64>::new(
+		Opts::new(
+			"relayer_rpc_endpoint_latency_ms",
+			"Average RPC latency in milliseconds of each endpoint of the chain"
+		),
+		&["chain_name", "endpoint"],
+	)
+	.unwrap();
+	pub static ref RPC_ENDPOINT_ERRORS: GaugeVec<U64> = GaugeVec::<U64>::new(
+		Opts::new(
+			"relayer_rpc_endpoint_consecutive_errors",
+			"Consecutive failed RPC calls of each endpoint of the chain"
+		),
+		&["chain_name", "endpoint"],
+	)
+	.unwrap();
+	pub static ref RPC_ENDPOINT_DEGRADED: GaugeVec<U64> = GaugeVec::<U64>::new(
+		Opts::new(
+			"relayer_rpc_endpoint_degraded",
+			"Whether each endpoint of the chain is degraded (1) or healthy (0)"
+		),
+		&["chain_name", "endpoint"],
+	)
+	.unwrap();
 	pub static ref PROCESS_UPTIME: Gauge<U64> = Gauge::<U64>::new(
 		"relayer_process_start_time_seconds",
 		"Number of seconds between the UNIX epoch and the moment the process started",
@@ -49,21 +124,164 @@ fn register_evm_prometheus_metrics(registry: &Registry) {
 	registry.register(Box::new(RPC_CALLS.clone())).unwrap();
 	registry.register(Box::new(NATIVE_BALANCE.clone())).unwrap();
 	registry.register(Box::new(PAYED_FEES.clone())).unwrap();
+	registry.register(Box::new(CURRENT_ROUND.clone())).unwrap();
+	registry.register(Box::new(SELECTION_TRANSITIONS.clone())).unwrap();
+	registry.register(Box::new(SHED_EVENTS.clone())).unwrap();
+	registry.register(Box::new(MISSING_RECEIPTS.clone())).unwrap();
+	registry.register(Box::new(MALFORMED_STREAM_ITEMS.clone())).unwrap();
+	registry.register(Box::new(BACKLOG_DEPTH.clone())).unwrap();
+	registry.register(Box::new(RPC_ENDPOINT_LATENCY.clone())).unwrap();
+	registry.register(Box::new(RPC_ENDPOINT_ERRORS.clone())).unwrap();
+	registry.register(Box::new(RPC_ENDPOINT_DEGRADED.clone())).unwrap();
 }
 
 /// Set the block height of the chain.
 pub fn set_block_height(label: &str, block_height: u64) {
 	BLOCK_HEIGHT.with_label_values(&[label]).set(block_height);
+
+	#[cfg(feature = "statsd")]
+	crate::statsd::gauge(&*BLOCK_HEIGHT, block_height as f64, &[("chain_name", label)]);
 }
 
 /// Increase the RPC call counter.
 pub fn increase_rpc_calls(label: &str) {
 	RPC_CALLS.with_label_values(&[label]).inc();
+
+	#[cfg(feature = "statsd")]
+	crate::statsd::counter(
+		&*RPC_CALLS,
+		RPC_CALLS.with_label_values(&[label]).get() as f64,
+		&[("chain_name", label)],
+	);
 }
 
 /// Set the native token balance remained of the chain.
 pub fn set_native_balance(label: &str, balance: f64) {
 	NATIVE_BALANCE.with_label_values(&[label]).set(balance);
+
+	#[cfg(feature = "statsd")]
+	crate::statsd::gauge(&*NATIVE_BALANCE, balance, &[("chain_name", label)]);
+}
+
+/// Set the latest observed round of the chain.
+pub fn set_current_round(label: &str, round: u64) {
+	CURRENT_ROUND.with_label_values(&[label]).set(round);
+
+	#[cfg(feature = "statsd")]
+	crate::statsd::gauge(&*CURRENT_ROUND, round as f64, &[("chain_name", label)]);
+}
+
+/// Increase the selection transition counter.
+pub fn increase_selection_transitions(label: &str, is_selected: bool) {
+	let direction = if is_selected { "selected" } else { "unselected" };
+	SELECTION_TRANSITIONS.with_label_values(&[label, direction]).inc();
+
+	#[cfg(feature = "statsd")]
+	crate::statsd::counter(
+		&*SELECTION_TRANSITIONS,
+		SELECTION_TRANSITIONS.with_label_values(&[label, direction]).get() as f64,
+		&[("chain_name", label), ("direction", direction)],
+	);
+}
+
+/// Increase the counter of events shed by the given buffer.
+pub fn increase_shed_events(label: &str, buffer: &str) {
+	SHED_EVENTS.with_label_values(&[label, buffer]).inc();
+
+	#[cfg(feature = "statsd")]
+	crate::statsd::counter(
+		&*SHED_EVENTS,
+		SHED_EVENTS.with_label_values(&[label, buffer]).get() as f64,
+		&[("chain_name", label), ("buffer", buffer)],
+	);
+}
+
+/// Increase the counter of sent transactions that returned no receipt.
+pub fn increase_missing_receipts(label: &str, resolution: &str) {
+	MISSING_RECEIPTS.with_label_values(&[label, resolution]).inc();
+
+	#[cfg(feature = "statsd")]
+	crate::statsd::counter(
+		&*MISSING_RECEIPTS,
+		MISSING_RECEIPTS.with_label_values(&[label, resolution]).get() as f64,
+		&[("chain_name", label), ("resolution", resolution)],
+	);
+}
+
+/// Increase the skipped malformed event stream items counter.
+pub fn increase_malformed_stream_items(label: &str) {
+	MALFORMED_STREAM_ITEMS.with_label_values(&[label]).inc();
+
+	#[cfg(feature = "statsd")]
+	crate::statsd::counter(
+		&*MALFORMED_STREAM_ITEMS,
+		MALFORMED_STREAM_ITEMS.with_label_values(&[label]).get() as f64,
+		&[("chain_name", label)],
+	);
+}
+
+/// Set the backlog depth of the given stage, and updates the total backlog depth.
+pub fn set_backlog_depth(label: &str, stage: &str, depth: u64) {
+	BACKLOG_DEPTH.with_label_values(&[label, stage]).set(depth);
+	update_backlog_total(label);
+}
+
+/// Increase the backlog depth of the given stage.
+pub fn increase_backlog_depth(label: &str, stage: &str) {
+	BACKLOG_DEPTH.with_label_values(&[label, stage]).inc();
+	update_backlog_total(label);
+}
+
+/// Decrease the backlog depth of the given stage.
+pub fn decrease_backlog_depth(label: &str, stage: &str) {
+	let gauge = BACKLOG_DEPTH.with_label_values(&[label, stage]);
+	gauge.set(gauge.get().saturating_sub(1));
+	update_backlog_total(label);
+}
+
+/// Returns the backlog depth of the given stage.
+pub fn backlog_depth(label: &str, stage: &str) -> u64 {
+	BACKLOG_DEPTH.with_label_values(&[label, stage]).get()
+}
+
+/// Sums up the backlog depth of every stage as `total`.
+fn update_backlog_total(label: &str) {
+	let total: u64 = BACKLOG_STAGES
+		.into_iter()
+		.map(|stage| BACKLOG_DEPTH.with_label_values(&[label, stage]).get())
+		.sum();
+	BACKLOG_DEPTH.with_label_values(&[label, "total"]).set(total);
+
+	#[cfg(feature = "statsd")]
+	for stage in BACKLOG_STAGES.into_iter().chain(["total"]) {
+		crate::statsd::gauge(
+			&*BACKLOG_DEPTH,
+			BACKLOG_DEPTH.with_label_values(&[label, stage]).get() as f64,
+			&[("chain_name", label), ("stage", stage)],
+		);
+	}
+}
+
+/// Set the health status of an RPC endpoint of the chain.
+pub fn set_rpc_endpoint_health(
+	label: &str,
+	endpoint: &str,
+	latency_ms: f64,
+	consecutive_errors: u32,
+	is_degraded: bool,
+) {
+	let labels = [label, endpoint];
+	RPC_ENDPOINT_LATENCY.with_label_values(&labels).set(latency_ms);
+	RPC_ENDPOINT_ERRORS.with_label_values(&labels).set(consecutive_errors as u64);
+	RPC_ENDPOINT_DEGRADED.with_label_values(&labels).set(is_degraded as u64);
+
+	#[cfg(feature = "statsd")]
+	{
+		let tags = [("chain_name", label), ("endpoint", endpoint)];
+		crate::statsd::gauge(&*RPC_ENDPOINT_LATENCY, latency_ms, &tags);
+		crate::statsd::gauge(&*RPC_ENDPOINT_ERRORS, consecutive_errors as f64, &tags);
+		crate::statsd::gauge(&*RPC_ENDPOINT_DEGRADED, is_degraded as u64 as f64, &tags);
+	}
 }
 
 /// Increase the payed transaction fees.
@@ -75,7 +293,14 @@ pub fn set_payed_fees(label: &str, receipt: &TransactionReceipt) {
 			.unwrap();
 		PAYED_FEES
 			.with_label_values(&[label])
-			.set(PAYED_FEES.with_label_values(&[label]).get() + payed_fee)
+			.set(PAYED_FEES.with_label_values(&[label]).get() + payed_fee);
+
+		#[cfg(feature = "statsd")]
+		crate::statsd::counter(
+			&*PAYED_FEES,
+			PAYED_FEES.with_label_values(&[label]).get(),
+			&[("chain_name", label)],
+		);
 	}
 }
 
@@ -84,4 +309,7 @@ fn set_system_uptime() {
 	let start_time_since_epoch =
 		SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default();
 	PROCESS_UPTIME.set(start_time_since_epoch.as_secs());
+
+	#[cfg(feature = "statsd")]
+	crate::statsd::gauge(&*PROCESS_UPTIME, start_time_since_epoch.as_secs() as f64, &[]);
 }
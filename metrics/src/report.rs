@@ -0,0 +1,148 @@
+use std::{
+	fs, io,
+	path::{Path, PathBuf},
+	thread,
+	time::{Duration, SystemTime},
+};
+
+use prometheus_endpoint::prometheus::{core::Collector, proto::MetricFamily, Encoder, TextEncoder};
+
+use crate::prometheus::{
+	BACKLOG_DEPTH, BLOCK_HEIGHT, CURRENT_ROUND, MALFORMED_STREAM_ITEMS, MISSING_RECEIPTS,
+	NATIVE_BALANCE, PAYED_FEES, PROCESS_UPTIME, RPC_CALLS, RPC_ENDPOINT_DEGRADED,
+	RPC_ENDPOINT_ERRORS, RPC_ENDPOINT_LATENCY, SELECTION_TRANSITIONS, SHED_EVENTS,
+};
+
+/// Collects the current values of every relayer metric.
+fn collect_metric_families() -> Vec<MetricFamily> {
+	let collectors: [&dyn Collector; 14] = [
+		&*PROCESS_UPTIME,
+		&*BLOCK_HEIGHT,
+		&*CURRENT_ROUND,
+		&*SELECTION_TRANSITIONS,
+		&*RPC_CALLS,
+		&*NATIVE_BALANCE,
+		&*PAYED_FEES,
+		&*SHED_EVENTS,
+		&*MISSING_RECEIPTS,
+		&*MALFORMED_STREAM_ITEMS,
+		&*BACKLOG_DEPTH,
+		&*RPC_ENDPOINT_LATENCY,
+		&*RPC_ENDPOINT_ERRORS,
+		&*RPC_ENDPOINT_DEGRADED,
+	];
+	collectors
+		.iter()
+		.flat_map(|collector| collector.collect())
+		.filter(|family| !family.get_metric().is_empty())
+		.collect()
+}
+
+/// Builds the summary report of the given metrics in the prometheus text format.
+fn build_report(families: &[MetricFamily], generated_at: SystemTime) -> String {
+	let mut buffer = vec![];
+	TextEncoder::new().encode(families, &mut buffer).unwrap_or_default();
+
+	format!(
+		"# Bifrost relayer summary report (generated at: {})\n{}",
+		generated_at
+			.duration_since(SystemTime::UNIX_EPOCH)
+			.unwrap_or_default()
+			.as_secs(),
+		String::from_utf8_lossy(&buffer)
+	)
+}
+
+/// Returns the path of the `index`-th rotated report. (e.g. `report.txt.1`)
+fn rotated_path(path: &Path, index: usize) -> PathBuf {
+	let mut rotated = path.as_os_str().to_owned();
+	rotated.push(format!(".{}", index));
+	rotated.into()
+}
+
+/// Writes the given report to the path. The previous reports are shifted to `<path>.1` up to
+/// `<path>.<max_files>`, and the oldest one is dropped.
+fn write_rotated(path: &Path, report: &str, max_files: usize) -> io::Result<()> {
+	if let Some(dir) = path.parent() {
+		fs::create_dir_all(dir)?;
+	}
+
+	if max_files > 0 {
+		for index in (1..max_files).rev() {
+			let from = rotated_path(path, index);
+			if from.exists() {
+				fs::rename(from, rotated_path(path, index + 1))?;
+			}
+		}
+		if path.exists() {
+			fs::rename(path, rotated_path(path, 1))?;
+		}
+	}
+	fs::write(path, report)
+}
+
+/// Starts writing the summary report to the given path periodically. Does nothing if the interval
+/// is zero.
+pub fn setup(path: impl AsRef<Path>, interval: Duration, max_files: usize) {
+	if interval.is_zero() {
+		return;
+	}
+
+	let path = path.as_ref().to_path_buf();
+	thread::spawn(move || loop {
+		thread::sleep(interval);
+
+		let report = build_report(&collect_metric_families(), SystemTime::now());
+		// reports are best-effort and should never interrupt the relayer.
+		let _ = write_rotated(&path, &report, max_files);
+	});
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn report_contains_relayer_metrics() {
+		crate::set_current_round("bifrost-testnet", 42);
+
+		let report = build_report(&collect_metric_families(), SystemTime::UNIX_EPOCH);
+		assert!(report.starts_with("# Bifrost relayer summary report (generated at: 0)\n"));
+		assert!(report.contains("relayer_current_round{chain_name=\"bifrost-testnet\"} 42"));
+	}
+
+	#[test]
+	fn backlog_depth_is_summed_up() {
+		crate::set_backlog_depth("backlog-test", "channel", 2);
+		crate::set_backlog_depth("backlog-test", "confirmation", 5);
+		crate::increase_backlog_depth("backlog-test", "submission");
+		crate::increase_backlog_depth("backlog-test", "submission");
+		crate::decrease_backlog_depth("backlog-test", "submission");
+
+		let report = build_report(&collect_metric_families(), SystemTime::UNIX_EPOCH);
+		assert!(report
+			.contains("relayer_backlog_depth{chain_name=\"backlog-test\",stage=\"submission\"} 1"));
+		assert!(
+			report.contains("relayer_backlog_depth{chain_name=\"backlog-test\",stage=\"total\"} 8")
+		);
+	}
+
+	#[test]
+	fn reports_are_rotated() {
+		let dir =
+			std::env::temp_dir().join(format!("bifrost-relayer-report-{}", std::process::id()));
+		let _ = fs::remove_dir_all(&dir);
+		let path = dir.join("report.txt");
+
+		for report in ["1", "2", "3", "4"] {
+			write_rotated(&path, report, 2).unwrap();
+		}
+
+		assert_eq!(fs::read_to_string(&path).unwrap(), "4");
+		assert_eq!(fs::read_to_string(rotated_path(&path, 1)).unwrap(), "3");
+		assert_eq!(fs::read_to_string(rotated_path(&path, 2)).unwrap(), "2");
+		assert!(!rotated_path(&path, 3).exists());
+
+		fs::remove_dir_all(&dir).unwrap();
+	}
+}
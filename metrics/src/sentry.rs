@@ -1,11 +1,44 @@
-use sentry::ClientInitGuard;
-use std::borrow::Cow;
+use sentry::{ClientInitGuard, Hub, SentryFuture, SentryFutureExt};
+use std::{borrow::Cow, future::Future};
 
-/// Builds a sentry client only when the sentry config exists.
+/// Attaches the full relayer address to every Sentry event as a tag. Messages only include the
+/// abbreviated address.
+pub fn set_sentry_relayer_address(address: &str) {
+	sentry::configure_scope(|scope| scope.set_tag("relayer_address", address));
+}
+
+/// Binds a new Sentry hub, forked from the current hub, to the given task. Handler tasks
+/// interleave on the same worker threads, so each must own its scope to keep the breadcrumbs of
+/// its event spans apart from the other handlers.
+pub fn bind_sentry_hub<F: Future>(future: F) -> SentryFuture<F> {
+	future.bind_hub(Hub::new_from_top(Hub::current()))
+}
+
+/// Starts the breadcrumb span of the event about to be processed. Breadcrumbs left by previously
+/// processed events are cleared, so that captures only describe the relevant event. The span
+/// lives in the scope of the current hub, so the calling task must be bound to its own hub by
+/// `bind_sentry_hub`.
+pub fn begin_sentry_event_span(message: String) {
+	sentry::configure_scope(|scope| scope.clear_breadcrumbs());
+	sentry::add_breadcrumb(sentry::Breadcrumb {
+		category: Some(String::from("event")),
+		message: Some(message),
+		..Default::default()
+	});
+}
+
+/// Ends the breadcrumb span of the processed event.
+pub fn end_sentry_event_span() {
+	sentry::configure_scope(|scope| scope.clear_breadcrumbs());
+}
+
+/// Builds a sentry client only when the sentry config exists. At most `max_breadcrumbs`
+/// breadcrumbs are attached to each capture.
 pub fn build_sentry_client(
 	is_enabled: bool,
 	dsn: String,
 	environment: Option<Cow<'static, str>>,
+	max_breadcrumbs: usize,
 ) -> Option<ClientInitGuard> {
 	if is_enabled && !dsn.is_empty() {
 		let sentry_client = sentry::init((
@@ -13,6 +46,7 @@ pub fn build_sentry_client(
 			sentry::ClientOptions {
 				release: sentry::release_name!(),
 				environment,
+				max_breadcrumbs,
 				..Default::default()
 			},
 		));
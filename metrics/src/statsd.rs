@@ -0,0 +1,223 @@
+use std::{
+	collections::BTreeMap,
+	io,
+	net::UdpSocket,
+	sync::{Mutex, OnceLock},
+	thread,
+	time::Duration,
+};
+
+use prometheus_endpoint::prometheus::core::Collector;
+
+/// The maximum payload size of a single statsd packet. Metrics are buffered until the next line
+/// would exceed this size. (=1432 bytes, fits in a single ethernet frame)
+const MAX_PACKET_SIZE: usize = 1432;
+
+/// The interval that buffered metrics are flushed even if the packet is not full. (=1s)
+const FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+static STATSD_CLIENT: OnceLock<StatsdClient> = OnceLock::new();
+
+/// The statsd client that buffers metric lines and sends them as UDP packets.
+struct StatsdClient {
+	/// The UDP socket connected to the statsd agent.
+	socket: UdpSocket,
+	/// The prefix prepended to every metric name.
+	prefix: Option<String>,
+	/// The buffered metric lines that are waiting to be flushed.
+	buffer: Mutex<String>,
+	/// The last sent total of each counter, keyed by its name and tags.
+	counter_totals: Mutex<BTreeMap<(String, Vec<(String, String)>), f64>>,
+}
+
+impl StatsdClient {
+	fn new(socket: UdpSocket, prefix: Option<String>) -> Self {
+		Self {
+			socket,
+			prefix,
+			buffer: Mutex::new(String::new()),
+			counter_totals: Mutex::new(BTreeMap::new()),
+		}
+	}
+
+	/// Appends a gauge line to the buffer.
+	fn gauge(&self, name: &str, value: f64, tags: &[(&str, &str)]) {
+		self.push(format_line(self.prefix.as_deref(), name, value, "g", tags));
+	}
+
+	/// Appends a counter line of the increase since the last sent total to the buffer. Nothing is
+	/// appended if the counter hasn't increased.
+	fn counter(&self, name: &str, total: f64, tags: &[(&str, &str)]) {
+		let key = (
+			name.to_string(),
+			tags.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect(),
+		);
+		let delta = {
+			let mut counter_totals = self.counter_totals.lock().unwrap();
+			let last = counter_totals.insert(key, total).unwrap_or_default();
+			total - last
+		};
+		if delta > 0.0 {
+			self.push(format_line(self.prefix.as_deref(), name, delta, "c", tags));
+		}
+	}
+
+	/// Appends a metric line to the buffer. The buffer is flushed first if the line doesn't fit.
+	fn push(&self, line: String) {
+		let mut buffer = self.buffer.lock().unwrap();
+		if !buffer.is_empty() && buffer.len() + line.len() + 1 > MAX_PACKET_SIZE {
+			self.send(&mut buffer);
+		}
+		if !buffer.is_empty() {
+			buffer.push('\n');
+		}
+		buffer.push_str(&line);
+	}
+
+	/// Sends every buffered metric line.
+	fn flush(&self) {
+		let mut buffer = self.buffer.lock().unwrap();
+		if !buffer.is_empty() {
+			self.send(&mut buffer);
+		}
+	}
+
+	/// Sends the given buffer as a single packet and clears it. Failures are ignored, since
+	/// metrics are best-effort and should never interrupt the relayer.
+	fn send(&self, buffer: &mut String) {
+		let _ = self.socket.send(buffer.as_bytes());
+		buffer.clear();
+	}
+}
+
+/// Builds a metric line of the given type (`g` or `c`) in the statsd format. The metric labels are
+/// attached as Datadog tags.
+fn format_line(
+	prefix: Option<&str>,
+	name: &str,
+	value: f64,
+	metric_type: &str,
+	tags: &[(&str, &str)],
+) -> String {
+	let mut line = match prefix {
+		Some(prefix) => format!("{}.{}:{}|{}", prefix, name, value, metric_type),
+		None => format!("{}:{}|{}", name, value, metric_type),
+	};
+	if !tags.is_empty() {
+		let tags: Vec<String> =
+			tags.iter().map(|(key, value)| format!("{}:{}", key, value)).collect();
+		line.push_str(&format!("|#{}", tags.join(",")));
+	}
+	line
+}
+
+/// Connects to the statsd agent and starts flushing buffered metrics periodically.
+pub fn setup(host: &str, port: u16, prefix: Option<String>) -> io::Result<()> {
+	let socket = UdpSocket::bind("0.0.0.0:0")?;
+	socket.connect((host, port))?;
+	socket.set_nonblocking(true)?;
+
+	if STATSD_CLIENT.set(StatsdClient::new(socket, prefix)).is_ok() {
+		thread::spawn(|| loop {
+			thread::sleep(FLUSH_INTERVAL);
+			if let Some(client) = STATSD_CLIENT.get() {
+				client.flush();
+			}
+		});
+	}
+	Ok(())
+}
+
+/// Mirrors the given prometheus metric to statsd as a gauge. Does nothing if statsd isn't set up.
+pub(crate) fn gauge(metric: &impl Collector, value: f64, tags: &[(&str, &str)]) {
+	if let Some(client) = STATSD_CLIENT.get() {
+		if let Some(desc) = metric.desc().first() {
+			client.gauge(&desc.fq_name, value, tags);
+		}
+	}
+}
+
+/// Mirrors the given monotonic prometheus metric to statsd as a counter, by sending the increase
+/// since the last mirrored total. Does nothing if statsd isn't set up.
+pub(crate) fn counter(metric: &impl Collector, total: f64, tags: &[(&str, &str)]) {
+	if let Some(client) = STATSD_CLIENT.get() {
+		if let Some(desc) = metric.desc().first() {
+			client.counter(&desc.fq_name, total, tags);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn gauge_lines_are_prefixed_and_tagged() {
+		assert_eq!(
+			format_line(
+				Some("bifrost"),
+				"relayer_block_height",
+				10.0,
+				"g",
+				&[("chain_name", "ethereum")]
+			),
+			"bifrost.relayer_block_height:10|g|#chain_name:ethereum"
+		);
+		assert_eq!(
+			format_line(
+				None,
+				"relayer_endpoint",
+				1.0,
+				"g",
+				&[("chain_name", "bsc"), ("endpoint", "primary")]
+			),
+			"relayer_endpoint:1|g|#chain_name:bsc,endpoint:primary"
+		);
+		assert_eq!(
+			format_line(None, "relayer_process_start_time_seconds", 1.5, "g", &[]),
+			"relayer_process_start_time_seconds:1.5|g"
+		);
+		assert_eq!(
+			format_line(None, "relayer_rpc_calls", 3.0, "c", &[("chain_name", "bsc")]),
+			"relayer_rpc_calls:3|c|#chain_name:bsc"
+		);
+	}
+
+	#[test]
+	fn buffered_gauges_are_flushed_in_packets() {
+		let agent = UdpSocket::bind("127.0.0.1:0").unwrap();
+		let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+		socket.connect(agent.local_addr().unwrap()).unwrap();
+		let client = StatsdClient::new(socket, None);
+
+		client.gauge("a", 1.0, &[]);
+		client.gauge("b", 2.0, &[]);
+		client.flush();
+
+		let mut packet = [0u8; MAX_PACKET_SIZE];
+		let size = agent.recv(&mut packet).unwrap();
+		assert_eq!(&packet[..size], b"a:1|g\nb:2|g");
+	}
+
+	#[test]
+	fn counters_are_sent_as_increases() {
+		let agent = UdpSocket::bind("127.0.0.1:0").unwrap();
+		let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+		socket.connect(agent.local_addr().unwrap()).unwrap();
+		let client = StatsdClient::new(socket, None);
+
+		client.counter("a", 2.0, &[("chain_name", "bsc")]);
+		client.counter("a", 5.0, &[("chain_name", "bsc")]);
+		client.counter("a", 1.0, &[("chain_name", "ethereum")]);
+		// unchanged totals are not sent
+		client.counter("a", 5.0, &[("chain_name", "bsc")]);
+		client.flush();
+
+		let mut packet = [0u8; MAX_PACKET_SIZE];
+		let size = agent.recv(&mut packet).unwrap();
+		assert_eq!(
+			&packet[..size],
+			b"a:2|c|#chain_name:bsc\na:3|c|#chain_name:bsc\na:1|c|#chain_name:ethereum"
+		);
+	}
+}
@@ -60,6 +60,10 @@ impl<T: JsonRpcClient> PeriodicWorker for HeartbeatSender<T> {
 						"authority.round_info",
 					)
 					.await;
+				if self.client.is_zeroed_value(round_info.is_zeroed(), "authority.round_info") {
+					self.wait_until_next_time().await;
+					continue;
+				}
 				self.request_send_transaction(
 					self.build_transaction(),
 					HeartbeatMetadata::new(
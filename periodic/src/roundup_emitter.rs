@@ -5,13 +5,14 @@ use ethers::{
 	abi::{encode, Detokenize, Token, Tokenize},
 	contract::EthLogDecode,
 	providers::JsonRpcClient,
-	types::{Address, Filter, Log, TransactionRequest, U256},
+	types::{Address, Filter, Log, TransactionRequest, U256, U64},
 };
 use tokio::time::sleep;
 
 use br_client::eth::{traits::BootstrapHandler, EthClient};
 use br_primitives::{
 	bootstrap::BootstrapSharedData,
+	bus::SystemEvent,
 	constants::{
 		cli::DEFAULT_BOOTSTRAP_ROUND_OFFSET,
 		config::BOOTSTRAP_BLOCK_CHUNK_SIZE,
@@ -68,6 +69,14 @@ impl<T: JsonRpcClient> PeriodicWorker for RoundupEmitter<T> {
 			self.wait_until_next_time().await;
 
 			let latest_round = self.get_latest_round().await;
+			if self.client.is_zeroed_value(latest_round.is_zero(), "authority.latest_round") {
+				continue;
+			}
+			if self.current_round.is_zero() {
+				// the initial round was zeroed, so there is no previous round to compare with
+				self.current_round = latest_round;
+				continue;
+			}
 
 			if self.current_round < latest_round {
 				log::info!(
@@ -77,7 +86,15 @@ impl<T: JsonRpcClient> PeriodicWorker for RoundupEmitter<T> {
 					self.current_round,
 					latest_round,
 				);
+				self.bootstrap_shared_data.event_bus.publish(SystemEvent::RoundAdvanced {
+					chain_id: self.client.get_chain_id(),
+					round: latest_round,
+				});
 
+				if self.client.wallet.is_observer() {
+					self.current_round = latest_round;
+					continue;
+				}
 				if !self.is_selected_relayer(latest_round).await {
 					continue;
 				}
@@ -211,12 +228,15 @@ impl<T: JsonRpcClient> RoundupEmitter<T> {
 
 	/// Get the latest round index.
 	async fn get_latest_round(&self) -> U256 {
-		self.client
+		let latest_round: U256 = self
+			.client
 			.contract_call(
 				self.client.protocol_contracts.authority.latest_round(),
 				"authority.latest_round",
 			)
-			.await
+			.await;
+		br_metrics::set_current_round(&self.client.get_chain_name(), latest_round.as_u64());
+		latest_round
 	}
 }
 
@@ -256,7 +276,9 @@ impl<T: JsonRpcClient> BootstrapHandler for RoundupEmitter<T> {
 				break;
 			} else if next_poll_round <= self.current_round {
 				// If RoundUp not reached to latest round, process round_control_poll
-				if self.is_selected_relayer(next_poll_round).await {
+				if !self.client.wallet.is_observer()
+					&& self.is_selected_relayer(next_poll_round).await
+				{
 					let new_relayers = self.fetch_validator_list(next_poll_round).await;
 					self.request_send_transaction(
 						self.build_transaction(next_poll_round, new_relayers.clone()),
@@ -283,7 +305,7 @@ impl<T: JsonRpcClient> BootstrapHandler for RoundupEmitter<T> {
 			}
 		}
 
-		for state in self.bootstrap_shared_data.bootstrap_states.write().await.iter_mut() {
+		for state in self.bootstrap_shared_data.bootstrap_states.write().await.values_mut() {
 			if *state == BootstrapState::BootstrapRoundUpPhase1 {
 				*state = BootstrapState::BootstrapRoundUpPhase2;
 			}
@@ -309,9 +331,8 @@ impl<T: JsonRpcClient> BootstrapHandler for RoundupEmitter<T> {
 				)
 				.await;
 
-			let latest_block_number = self.client.get_latest_block_number().await;
-			let mut from_block = latest_block_number.saturating_sub(bootstrap_offset_height);
-			let to_block = latest_block_number;
+			let (mut from_block, to_block) =
+				self.client.get_bootstrap_range(bootstrap_offset_height, U64::zero()).await;
 
 			// Split from_block into smaller chunks
 			while from_block <= to_block {
@@ -357,7 +378,7 @@ impl<T: JsonRpcClient> BootstrapHandler for RoundupEmitter<T> {
 			.bootstrap_states
 			.read()
 			.await
-			.iter()
+			.values()
 			.all(|s| *s == state)
 	}
 }
@@ -2,6 +2,8 @@ use std::{collections::BTreeMap, str::FromStr, sync::Arc};
 
 use br_client::eth::{traits::SocketRelayBuilder, EthClient};
 use br_primitives::{
+	abbreviate_address,
+	buffer::{BoundedBuffer, BufferInsertion, SheddingPolicy},
 	constants::{
 		errors::{INVALID_BIFROST_NATIVENESS, INVALID_CHAIN_ID, INVALID_PERIODIC_SCHEDULE},
 		schedule::{ROLLBACK_CHECK_MINIMUM_INTERVAL, ROLLBACK_CHECK_SCHEDULE},
@@ -34,7 +36,7 @@ pub struct SocketRollbackEmitter<T> {
 	/// The receiver connected to the socket rollback channel.
 	rollback_receiver: UnboundedReceiver<SocketMessage>,
 	/// The local storage saving emitted `Socket` event messages.
-	rollback_msgs: BTreeMap<RawRequestID, RollbackableMessage>,
+	rollback_msgs: BoundedBuffer<RawRequestID, RollbackableMessage>,
 	/// The sender that sends messages to the tx request channel.
 	tx_request_sender: Arc<TxRequestSender>,
 	/// The time schedule that represents when to check heartbeat pulsed.
@@ -46,6 +48,8 @@ impl<T: JsonRpcClient> SocketRollbackEmitter<T> {
 	pub fn new(
 		tx_request_sender: Arc<TxRequestSender>,
 		system_clients_vec: Vec<Arc<EthClient<T>>>,
+		max_buffered_events: usize,
+		shedding_policy: SheddingPolicy,
 	) -> (Self, UnboundedSender<SocketMessage>) {
 		let (sender, rollback_receiver) = mpsc::unbounded_channel::<SocketMessage>();
 
@@ -59,7 +63,7 @@ impl<T: JsonRpcClient> SocketRollbackEmitter<T> {
 				client: system_clients.get(&tx_request_sender.id).expect(INVALID_CHAIN_ID).clone(),
 				system_clients,
 				rollback_receiver,
-				rollback_msgs: BTreeMap::new(),
+				rollback_msgs: BoundedBuffer::new(max_buffered_events, shedding_policy),
 				tx_request_sender,
 				schedule: Schedule::from_str(ROLLBACK_CHECK_SCHEDULE)
 					.expect(INVALID_PERIODIC_SCHEDULE),
@@ -200,6 +204,31 @@ impl<T: JsonRpcClient> SocketRollbackEmitter<T> {
 		self.request_send_transaction(tx_request, metadata, true, GasCoefficient::Low);
 	}
 
+	/// Alerts the rollbackable socket message shed due to a full buffer. A shed message will
+	/// never be rolled back, so it requires a manual rollback.
+	fn alert_shed_message(&self, action: &str, req_id: u128) {
+		br_metrics::increase_shed_events(&self.client.get_chain_name(), "rollback");
+		log::error!(
+			target: &self.client.get_chain_name(),
+			"-[{}] ❗️ Rollbackable Socket buffer is full. {}: {}",
+			sub_display_format(SUB_LOG_TARGET),
+			action,
+			req_id,
+		);
+		sentry::capture_message(
+			format!(
+				"[{}]-[{}]-[{}] ❗️ Rollbackable Socket buffer is full. {}: {}",
+				&self.client.get_chain_name(),
+				SUB_LOG_TARGET,
+				abbreviate_address(&self.client.address()),
+				action,
+				req_id,
+			)
+			.as_str(),
+			sentry::Level::Error,
+		);
+	}
+
 	/// Tries to receive any new rollbackable messages and store's it locally.
 	/// The timestamp will be set to the current highest block's timestamp.
 	fn receive(&mut self, current_timestamp: U256) {
@@ -209,8 +238,19 @@ impl<T: JsonRpcClient> SocketRollbackEmitter<T> {
 			if self.rollback_msgs.contains_key(&req_id) {
 				continue;
 			}
-			self.rollback_msgs
-				.insert(req_id, RollbackableMessage::new(current_timestamp, msg));
+			match self
+				.rollback_msgs
+				.insert(req_id, RollbackableMessage::new(current_timestamp, msg))
+			{
+				BufferInsertion::Inserted => (),
+				BufferInsertion::DroppedOldest(dropped_req_id) => {
+					self.alert_shed_message("Dropped the oldest message", dropped_req_id);
+				},
+				BufferInsertion::Rejected => {
+					self.alert_shed_message("Rejected the new message", req_id);
+					continue;
+				},
+			}
 
 			log::info!(
 				target: &self.client.get_chain_name(),
@@ -304,11 +344,11 @@ impl<T: JsonRpcClient> PeriodicWorker for SocketRollbackEmitter<T> {
 			{
 				self.receive(latest_block.timestamp);
 
-				for (req_id, rollback_msg) in self.rollback_msgs.clone() {
+				for (req_id, rollback_msg) in self.rollback_msgs.clone().iter() {
 					// ignore if the request has already been processed.
 					// it should be removed from the local storage.
 					if self.is_request_executed(&rollback_msg.socket_msg).await {
-						handled_req_ids.push(req_id);
+						handled_req_ids.push(*req_id);
 						continue;
 					}
 					// ignore if the required interval didn't pass.
@@ -319,7 +359,7 @@ impl<T: JsonRpcClient> PeriodicWorker for SocketRollbackEmitter<T> {
 					}
 					// the pending request has not been processed in the waiting period. rollback should be handled.
 					self.try_rollback(&rollback_msg.socket_msg).await;
-					handled_req_ids.push(req_id);
+					handled_req_ids.push(*req_id);
 				}
 			}
 			for req_id in handled_req_ids {
@@ -1,12 +1,55 @@
-use std::sync::Arc;
+use std::{
+	collections::{BTreeMap, VecDeque},
+	fmt::{Display, Formatter},
+	sync::Arc,
+	time::{Duration, Instant},
+};
 
-use tokio::sync::{Barrier, Mutex, RwLock};
+use tokio::{
+	sync::{Barrier, Mutex, RwLock},
+	time::sleep,
+};
 
 use crate::{
+	bus::SystemEventBus,
+	checkpoint::{chunk_ranges, CheckpointStore},
 	cli::{BootstrapConfig, Configuration},
-	eth::BootstrapState,
+	constants::config::{DEFAULT_DEAD_LETTER_CAPACITY, TRANSITIONAL_STATE_LOG_INTERVAL_MS},
+	dead_letter::DeadLetterQueue,
+	eth::{BootstrapState, ChainID},
+	health::HealthState,
+	store::FileStateStore,
 };
 
+/// The first block of the bootstrap window.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BootstrapStart {
+	/// The requested offset fits in the available chain history.
+	Offset(u64),
+	/// The requested offset exceeds the available chain history (e.g. a freshly-reset devnet),
+	/// so the whole history is bootstrapped from the floor.
+	ChainHistory(u64),
+}
+
+impl BootstrapStart {
+	/// Resolves the first block of the window that ends at `latest_block` and spans
+	/// `offset_height` blocks. The window never starts before `floor` (e.g. the deployment block).
+	pub fn new(latest_block: u64, offset_height: u64, floor: u64) -> Self {
+		if offset_height > latest_block.saturating_sub(floor) {
+			Self::ChainHistory(floor)
+		} else {
+			Self::Offset(latest_block - offset_height)
+		}
+	}
+
+	/// Returns the first block of the window.
+	pub fn block(&self) -> u64 {
+		match self {
+			Self::Offset(block) | Self::ChainHistory(block) => *block,
+		}
+	}
+}
+
 #[derive(Clone)]
 pub struct BootstrapSharedData {
 	/// The barrier used to lock the system until the socket bootstrap process is done.
@@ -17,10 +60,18 @@ pub struct BootstrapSharedData {
 	pub socket_bootstrap_count: Arc<Mutex<u8>>,
 	/// The current number of finished roundup bootstrap processes.
 	pub roundup_bootstrap_count: Arc<Mutex<u8>>,
-	/// The current shared state of the bootstrap process.
-	pub bootstrap_states: Arc<RwLock<Vec<BootstrapState>>>,
+	/// The current bootstrap state of each chain.
+	pub bootstrap_states: Arc<RwLock<BTreeMap<ChainID, BootstrapState>>>,
 	/// The bootstrap configurations.
 	pub bootstrap_config: Option<BootstrapConfig>,
+	/// The internal event bus that publishes the bootstrap completion of each chain.
+	pub event_bus: SystemEventBus,
+	/// The store of bootstrap checkpoints. Bootstrap won't be resumable if this is `None`.
+	pub checkpoint_store: Option<CheckpointStore>,
+	/// The health state reported by each chain's block polling loop.
+	pub health_state: Arc<HealthState>,
+	/// The queue of the events that failed to be decoded. Disabled if this is `None`.
+	pub dead_letter_queue: Option<Arc<DeadLetterQueue>>,
 }
 
 impl BootstrapSharedData {
@@ -46,7 +97,12 @@ impl BootstrapSharedData {
 		));
 		let socket_bootstrap_count = Arc::new(Mutex::new(u8::default()));
 		let roundup_bootstrap_count = Arc::new(Mutex::new(u8::default()));
-		let bootstrap_states = Arc::new(RwLock::new(vec![bootstrap_states; evm_providers.len()]));
+		let bootstrap_states = Arc::new(RwLock::new(
+			evm_providers
+				.iter()
+				.map(|evm_provider| (evm_provider.id, bootstrap_states.clone()))
+				.collect(),
+		));
 
 		Self {
 			socket_barrier,
@@ -55,6 +111,323 @@ impl BootstrapSharedData {
 			roundup_bootstrap_count,
 			bootstrap_states,
 			bootstrap_config: bootstrap_config.clone(),
+			event_bus: SystemEventBus::new(),
+			checkpoint_store: bootstrap_config
+				.as_ref()
+				.and_then(|bootstrap_config| bootstrap_config.checkpoint_dir.as_ref())
+				.map(|dir| CheckpointStore::new(Arc::new(FileStateStore::new(dir)))),
+			health_state: Arc::new(HealthState::default()),
+			dead_letter_queue: config
+				.relayer_config
+				.dead_letter_config
+				.as_ref()
+				.filter(|dead_letter_config| dead_letter_config.is_enabled)
+				.map(|dead_letter_config| {
+					Arc::new(DeadLetterQueue::new(
+						Arc::new(FileStateStore::new(&dead_letter_config.dir)),
+						dead_letter_config.capacity.unwrap_or(DEFAULT_DEAD_LETTER_CAPACITY),
+						dead_letter_config.shedding_policy.unwrap_or_default(),
+					))
+				}),
+		}
+	}
+}
+
+/// Transitions the bootstrap state of the given chain to `to`, only if it's currently in `from`.
+/// Returns `true` if the state has been transitioned.
+pub fn transition_chain_state(
+	bootstrap_states: &mut BTreeMap<ChainID, BootstrapState>,
+	chain_id: ChainID,
+	from: BootstrapState,
+	to: BootstrapState,
+) -> bool {
+	match bootstrap_states.get_mut(&chain_id) {
+		Some(state) if *state == from => {
+			*state = to;
+			true
+		},
+		_ => false,
+	}
+}
+
+/// The scope of a bootstrap process on a single chain, computed before any event is fetched.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BootstrapPlan {
+	/// The first block of the bootstrap window.
+	pub from_block: u64,
+	/// The last block of the bootstrap window.
+	pub to_block: u64,
+	/// The checkpoint to resume after, if it's inside the bootstrap window.
+	pub checkpoint: Option<u64>,
+	/// The block range chunk size for getLogs requests.
+	pub chunk_size: u64,
+}
+
+impl BootstrapPlan {
+	pub fn new(from_block: u64, to_block: u64, checkpoint: Option<u64>, chunk_size: u64) -> Self {
+		Self {
+			from_block,
+			to_block,
+			checkpoint: checkpoint.filter(|checkpoint| *checkpoint >= from_block),
+			chunk_size,
+		}
+	}
+
+	/// Returns the block ranges to fetch, skipping the blocks up to the checkpoint.
+	pub fn chunks(&self) -> Vec<(u64, u64)> {
+		chunk_ranges(self.from_block, self.to_block, self.chunk_size, self.checkpoint)
+	}
+}
+
+impl Display for BootstrapPlan {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(
+			f,
+			"window(#{}..#{}, {} blocks), chunks({})",
+			self.from_block,
+			self.to_block,
+			(self.to_block + 1).saturating_sub(self.from_block),
+			self.chunks().len(),
+		)?;
+		if let Some(checkpoint) = self.checkpoint {
+			write!(f, ", resume after #{}", checkpoint)?;
+		}
+		Ok(())
+	}
+}
+
+/// Estimates the remaining time of a bootstrap from the number of blocks scanned per unit time.
+/// The rate is measured over a sliding window, so that it adapts to a changing scan speed.
+#[derive(Debug)]
+pub struct BootstrapEstimator {
+	/// The total number of blocks to scan.
+	total_blocks: u64,
+	/// The length of the sliding window.
+	window: Duration,
+	/// The number of blocks scanned so far, sampled at each moment. The oldest sample is the
+	/// newest one outside the window, which is used as the base of the rate.
+	samples: VecDeque<(Instant, u64)>,
+}
+
+impl BootstrapEstimator {
+	/// Instantiates a new `BootstrapEstimator` for a bootstrap started at the given moment.
+	pub fn new(total_blocks: u64, window: Duration, started_at: Instant) -> Self {
+		Self { total_blocks, window, samples: VecDeque::from([(started_at, 0)]) }
+	}
+
+	/// Records the number of blocks scanned so far.
+	pub fn record(&mut self, now: Instant, scanned_blocks: u64) {
+		self.samples.push_back((now, scanned_blocks));
+		while self.samples.len() > 2
+			&& now.saturating_duration_since(self.samples[1].0) >= self.window
+		{
+			self.samples.pop_front();
+		}
+	}
+
+	/// Returns the number of blocks left to scan.
+	pub fn remaining_blocks(&self) -> u64 {
+		let scanned_blocks = self.samples.back().map(|(_, blocks)| *blocks).unwrap_or_default();
+		self.total_blocks.saturating_sub(scanned_blocks)
+	}
+
+	/// Returns the scan rate in blocks per second. `None` if no rate can be measured yet.
+	pub fn rate(&self) -> Option<f64> {
+		let ((first_at, first_blocks), (last_at, last_blocks)) =
+			(self.samples.front()?, self.samples.back()?);
+		let elapsed = last_at.saturating_duration_since(*first_at).as_secs_f64();
+		let scanned_blocks = last_blocks.saturating_sub(*first_blocks);
+		if elapsed == 0.0 || scanned_blocks == 0 {
+			return None;
+		}
+		Some(scanned_blocks as f64 / elapsed)
+	}
+
+	/// Returns the estimated remaining time. `None` if no rate can be measured yet.
+	pub fn eta(&self) -> Option<Duration> {
+		let remaining_blocks = self.remaining_blocks();
+		if remaining_blocks == 0 {
+			return Some(Duration::ZERO);
+		}
+		self.rate().map(|rate| Duration::from_secs_f64(remaining_blocks as f64 / rate))
+	}
+}
+
+impl Display for BootstrapEstimator {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(
+			f,
+			"{}/{} blocks, ",
+			self.total_blocks - self.remaining_blocks(),
+			self.total_blocks
+		)?;
+		match self.eta() {
+			Some(eta) => write!(f, "eta {}s", eta.as_secs()),
+			None => write!(f, "eta unknown"),
 		}
 	}
 }
+
+/// Limits a repetitive log to once per interval.
+#[derive(Debug)]
+pub struct LogThrottle {
+	/// The interval between each log.
+	interval: Duration,
+	/// The moment of the latest log.
+	last_logged_at: Option<Instant>,
+}
+
+impl LogThrottle {
+	/// Instantiates a new `LogThrottle` that allows a log once per the given interval.
+	pub fn new(interval: Duration) -> Self {
+		Self { interval, last_logged_at: None }
+	}
+
+	/// Returns `true` if a log should be emitted. Only once per interval returns `true`.
+	pub fn should_log(&mut self, now: Instant) -> bool {
+		let should_log = match self.last_logged_at {
+			Some(last_logged_at) => now.saturating_duration_since(last_logged_at) >= self.interval,
+			None => true,
+		};
+		if should_log {
+			self.last_logged_at = Some(now);
+		}
+		should_log
+	}
+}
+
+/// Paces a handler while the bootstrap states are transitional, which means that they haven't
+/// been synced to any state that the handler acts on. Instead of hot-looping, the handler sleeps
+/// between each state check and only logs periodically.
+pub struct TransitionalStateWaiter {
+	/// The interval between each state check.
+	interval: Duration,
+	/// The throttle of the logs emitted while waiting.
+	log_throttle: LogThrottle,
+}
+
+impl TransitionalStateWaiter {
+	/// Instantiates a new `TransitionalStateWaiter` that checks the states at the given interval.
+	pub fn new(interval: Duration) -> Self {
+		Self {
+			interval,
+			log_throttle: LogThrottle::new(Duration::from_millis(
+				TRANSITIONAL_STATE_LOG_INTERVAL_MS,
+			)),
+		}
+	}
+
+	/// Returns `true` if a log should be emitted. Only once per log interval returns `true`.
+	pub fn should_log(&mut self, now: Instant) -> bool {
+		self.log_throttle.should_log(now)
+	}
+
+	/// Sleeps until the next state check.
+	pub async fn wait(&self) {
+		sleep(self.interval).await;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn bootstrap_states_are_tracked_per_chain() {
+		let mut bootstrap_states: BTreeMap<ChainID, BootstrapState> =
+			[(1, BootstrapState::NodeSyncing), (3068, BootstrapState::NodeSyncing)].into();
+
+		// only the synced chain transitions, the lagging one stays behind.
+		assert!(transition_chain_state(
+			&mut bootstrap_states,
+			3068,
+			BootstrapState::NodeSyncing,
+			BootstrapState::BootstrapRoundUpPhase1,
+		));
+		assert_eq!(
+			bootstrap_states,
+			[(1, BootstrapState::NodeSyncing), (3068, BootstrapState::BootstrapRoundUpPhase1)]
+				.into()
+		);
+
+		// states other than `from` and unknown chains are left untouched.
+		assert!(!transition_chain_state(
+			&mut bootstrap_states,
+			3068,
+			BootstrapState::NodeSyncing,
+			BootstrapState::BootstrapRoundUpPhase1,
+		));
+		assert!(!transition_chain_state(
+			&mut bootstrap_states,
+			5,
+			BootstrapState::NodeSyncing,
+			BootstrapState::BootstrapRoundUpPhase1,
+		));
+		assert_eq!(bootstrap_states.get(&1), Some(&BootstrapState::NodeSyncing));
+	}
+
+	#[test]
+	fn offset_beyond_short_chain_starts_from_history() {
+		assert_eq!(BootstrapStart::new(10_000, 1_000, 0), BootstrapStart::Offset(9_000));
+		assert_eq!(BootstrapStart::new(1_000, 1_000, 0), BootstrapStart::Offset(0));
+
+		// a freshly-reset chain shorter than the offset
+		assert_eq!(BootstrapStart::new(50, 1_000, 0), BootstrapStart::ChainHistory(0));
+		assert_eq!(BootstrapStart::new(0, 1_000, 0).block(), 0);
+
+		// the deployment block bounds the history
+		assert_eq!(BootstrapStart::new(1_500, 1_000, 800), BootstrapStart::ChainHistory(800));
+		assert_eq!(BootstrapStart::new(1_500, 1_000, 500), BootstrapStart::Offset(500));
+	}
+
+	#[test]
+	fn bootstrap_plan_summarizes_scope() {
+		let plan = BootstrapPlan::new(1_000, 5_999, None, 2_000);
+		assert_eq!(plan.chunks().len(), 3);
+		assert_eq!(plan.to_string(), "window(#1000..#5999, 5000 blocks), chunks(3)");
+
+		let plan = BootstrapPlan::new(1_000, 5_999, Some(2_999), 2_000);
+		assert_eq!(plan.chunks(), vec![(3_000, 4_999), (5_000, 5_999)]);
+		assert_eq!(
+			plan.to_string(),
+			"window(#1000..#5999, 5000 blocks), chunks(2), resume after #2999"
+		);
+
+		// checkpoints outside the window are ignored
+		assert_eq!(BootstrapPlan::new(1_000, 5_999, Some(10), 2_000).checkpoint, None);
+	}
+
+	#[test]
+	fn estimates_eta_over_sliding_window() {
+		let started_at = Instant::now();
+		let mut estimator = BootstrapEstimator::new(10_000, Duration::from_secs(50), started_at);
+
+		// no rate is available until a chunk has been scanned
+		assert_eq!(estimator.eta(), None);
+		assert_eq!(estimator.to_string(), "0/10000 blocks, eta unknown");
+
+		estimator.record(started_at + Duration::from_secs(10), 1_000);
+		assert_eq!(estimator.rate(), Some(100.0));
+		assert_eq!(estimator.eta(), Some(Duration::from_secs(90)));
+
+		// the scan slows down, which is reflected once the earlier samples leave the window
+		estimator.record(started_at + Duration::from_secs(60), 2_000);
+		estimator.record(started_at + Duration::from_secs(110), 3_000);
+		assert_eq!(estimator.rate(), Some(20.0));
+		assert_eq!(estimator.eta(), Some(Duration::from_secs(350)));
+		assert_eq!(estimator.to_string(), "3000/10000 blocks, eta 350s");
+
+		estimator.record(started_at + Duration::from_secs(120), 10_000);
+		assert_eq!(estimator.eta(), Some(Duration::ZERO));
+	}
+
+	#[test]
+	fn logs_once_per_interval() {
+		let mut waiter = TransitionalStateWaiter::new(Duration::from_millis(10));
+		let now = Instant::now();
+
+		assert!(waiter.should_log(now));
+		assert!(!waiter.should_log(now + Duration::from_secs(1)));
+		assert!(waiter.should_log(now + Duration::from_millis(TRANSITIONAL_STATE_LOG_INTERVAL_MS)));
+	}
+}
@@ -0,0 +1,165 @@
+use std::collections::{BTreeMap, VecDeque};
+
+use serde::Deserialize;
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq)]
+/// The policy applied when a bounded buffer is full. Rejecting the new entry is the default, so
+/// that an entry already being tracked is never silently dropped.
+pub enum SheddingPolicy {
+	/// Drops the oldest buffered entry to make room for the new entry.
+	DropOldest,
+	/// Rejects the new entry and keeps the buffered entries.
+	#[default]
+	RejectNewest,
+}
+
+#[derive(Debug, PartialEq)]
+/// The result of an insertion to a bounded buffer.
+pub enum BufferInsertion<K> {
+	/// The entry has been buffered.
+	Inserted,
+	/// The entry has been buffered and the oldest entry with the given key has been dropped.
+	DroppedOldest(K),
+	/// The entry has been rejected since the buffer is full.
+	Rejected,
+}
+
+#[derive(Clone, Debug)]
+/// A keyed buffer that never holds more than `capacity` entries. Once full, the configured
+/// `SheddingPolicy` decides which entry will be shed.
+pub struct BoundedBuffer<K, V> {
+	/// The buffered entries.
+	entries: BTreeMap<K, V>,
+	/// The keys in insertion order. The front is the oldest entry.
+	order: VecDeque<K>,
+	/// The maximum number of buffered entries.
+	capacity: usize,
+	/// The policy applied when the buffer is full.
+	policy: SheddingPolicy,
+}
+
+impl<K: Ord + Clone, V> BoundedBuffer<K, V> {
+	/// Instantiates a new `BoundedBuffer` instance.
+	pub fn new(capacity: usize, policy: SheddingPolicy) -> Self {
+		Self { entries: BTreeMap::new(), order: VecDeque::new(), capacity, policy }
+	}
+
+	/// Buffers the given entry. An entry with an existing key replaces the previous value.
+	pub fn insert(&mut self, key: K, value: V) -> BufferInsertion<K> {
+		if self.entries.contains_key(&key) {
+			self.entries.insert(key, value);
+			return BufferInsertion::Inserted;
+		}
+
+		let mut insertion = BufferInsertion::Inserted;
+		if self.entries.len() >= self.capacity {
+			match self.policy {
+				SheddingPolicy::RejectNewest => return BufferInsertion::Rejected,
+				SheddingPolicy::DropOldest => match self.order.pop_front() {
+					Some(oldest) => {
+						self.entries.remove(&oldest);
+						insertion = BufferInsertion::DroppedOldest(oldest);
+					},
+					// a zero capacity buffer can't hold anything
+					None => return BufferInsertion::Rejected,
+				},
+			}
+		}
+
+		self.order.push_back(key.clone());
+		self.entries.insert(key, value);
+		insertion
+	}
+
+	/// Removes the entry of the given key.
+	pub fn remove(&mut self, key: &K) -> Option<V> {
+		let value = self.entries.remove(key);
+		if value.is_some() {
+			self.order.retain(|k| k != key);
+		}
+		value
+	}
+
+	/// Returns `true` if the buffer contains an entry of the given key.
+	pub fn contains_key(&self, key: &K) -> bool {
+		self.entries.contains_key(key)
+	}
+
+	/// Returns an iterator over the buffered entries, sorted by key.
+	pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+		self.entries.iter()
+	}
+
+	/// Removes every buffered entry, sorted by key.
+	pub fn drain(&mut self) -> Vec<(K, V)> {
+		self.order.clear();
+		std::mem::take(&mut self.entries).into_iter().collect()
+	}
+
+	/// Returns the number of buffered entries.
+	pub fn len(&self) -> usize {
+		self.entries.len()
+	}
+
+	/// Returns `true` if nothing has been buffered.
+	pub fn is_empty(&self) -> bool {
+		self.entries.is_empty()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn filled_buffer(policy: SheddingPolicy) -> BoundedBuffer<u128, &'static str> {
+		let mut buffer = BoundedBuffer::new(3, policy);
+		// the insertion order differs from the key order
+		assert_eq!(buffer.insert(2, "b"), BufferInsertion::Inserted);
+		assert_eq!(buffer.insert(1, "a"), BufferInsertion::Inserted);
+		assert_eq!(buffer.insert(3, "c"), BufferInsertion::Inserted);
+		buffer
+	}
+
+	#[test]
+	fn drop_oldest_sheds_the_first_inserted_entry() {
+		let mut buffer = filled_buffer(SheddingPolicy::DropOldest);
+
+		assert_eq!(buffer.insert(4, "d"), BufferInsertion::DroppedOldest(2));
+		assert_eq!(buffer.len(), 3);
+		assert!(!buffer.contains_key(&2));
+		assert!(buffer.contains_key(&4));
+
+		assert_eq!(buffer.insert(5, "e"), BufferInsertion::DroppedOldest(1));
+		assert_eq!(buffer.iter().map(|(k, _)| *k).collect::<Vec<_>>(), vec![3, 4, 5]);
+	}
+
+	#[test]
+	fn reject_newest_keeps_the_buffered_entries() {
+		let mut buffer = filled_buffer(SheddingPolicy::default());
+
+		assert_eq!(buffer.insert(4, "d"), BufferInsertion::Rejected);
+		assert_eq!(buffer.iter().map(|(k, _)| *k).collect::<Vec<_>>(), vec![1, 2, 3]);
+
+		// removing an entry makes room again
+		assert_eq!(buffer.remove(&1), Some("a"));
+		assert_eq!(buffer.insert(4, "d"), BufferInsertion::Inserted);
+		assert_eq!(buffer.len(), 3);
+	}
+
+	#[test]
+	fn existing_keys_are_replaced_without_shedding() {
+		let mut buffer = filled_buffer(SheddingPolicy::RejectNewest);
+
+		assert_eq!(buffer.insert(1, "z"), BufferInsertion::Inserted);
+		assert_eq!(buffer.iter().find(|(k, _)| **k == 1), Some((&1, &"z")));
+	}
+
+	#[test]
+	fn drained_buffer_makes_room_again() {
+		let mut buffer = filled_buffer(SheddingPolicy::RejectNewest);
+
+		assert_eq!(buffer.drain(), vec![(1, "a"), (2, "b"), (3, "c")]);
+		assert!(buffer.is_empty());
+		assert_eq!(buffer.insert(4, "d"), BufferInsertion::Inserted);
+	}
+}
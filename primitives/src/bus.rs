@@ -0,0 +1,81 @@
+use ethers::types::U256;
+use tokio::sync::broadcast::{self, Receiver, Sender};
+
+use crate::eth::ChainID;
+
+/// The capacity of the system event channel.
+const SYSTEM_EVENT_BUS_CAPACITY: usize = 64;
+
+#[derive(Clone, Debug, PartialEq)]
+/// The system wide events published on the internal event bus.
+pub enum SystemEvent {
+	/// The bootstrap process of the given chain has started with the given plan.
+	BootstrapStarted { chain_id: ChainID, plan: String },
+	/// The bootstrap process of the given chain has been completed.
+	BootstrapCompleted { chain_id: ChainID },
+	/// The relayer has entered (`is_selected = true`) or left the selected relayer set at the
+	/// given round.
+	SelectionChanged { chain_id: ChainID, round: U256, is_selected: bool },
+	/// The given (native) chain has advanced to the given round.
+	RoundAdvanced { chain_id: ChainID, round: U256 },
+	/// Relaying on the given chain has been paused for the given reason.
+	RelayPaused { chain_id: ChainID, reason: String },
+	/// Relaying on the given chain has been resumed for the given reason.
+	RelayResumed { chain_id: ChainID, reason: String },
+}
+
+#[derive(Clone, Debug)]
+/// The internal event bus that lets subsystems react to each other's state changes.
+pub struct SystemEventBus {
+	/// The channel sending system events.
+	sender: Sender<SystemEvent>,
+}
+
+impl SystemEventBus {
+	/// Instantiates a new `SystemEventBus` instance.
+	pub fn new() -> Self {
+		let (sender, _receiver) = broadcast::channel(SYSTEM_EVENT_BUS_CAPACITY);
+		Self { sender }
+	}
+
+	/// Publishes the event to every subscriber. The event is dropped if nothing has subscribed.
+	pub fn publish(&self, event: SystemEvent) {
+		let _ = self.sender.send(event);
+	}
+
+	/// Subscribes to the events published after this call.
+	pub fn subscribe(&self) -> Receiver<SystemEvent> {
+		self.sender.subscribe()
+	}
+}
+
+impl Default for SystemEventBus {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[tokio::test]
+	async fn subscriber_receives_per_chain_bootstrap_completions() {
+		let bus = SystemEventBus::new();
+		let mut receiver = bus.subscribe();
+
+		bus.publish(SystemEvent::BootstrapCompleted { chain_id: 3068 });
+		bus.publish(SystemEvent::BootstrapCompleted { chain_id: 1 });
+
+		assert_eq!(
+			receiver.recv().await.unwrap(),
+			SystemEvent::BootstrapCompleted { chain_id: 3068 }
+		);
+		assert_eq!(receiver.recv().await.unwrap(), SystemEvent::BootstrapCompleted { chain_id: 1 });
+	}
+
+	#[test]
+	fn publish_without_subscribers_is_ignored() {
+		SystemEventBus::new().publish(SystemEvent::BootstrapCompleted { chain_id: 1 });
+	}
+}
@@ -0,0 +1,116 @@
+use std::{io, sync::Arc};
+
+use crate::{eth::ChainID, store::StateStore};
+
+/// Persists the last block that the bootstrap process has completely handled on each chain. The
+/// checkpoint is flushed after every chunk whose relays have settled, so that an interrupted
+/// bootstrap can be resumed from the last chunk boundary. It's cleared once the bootstrap has been
+/// completed, so that a later bootstrap scans its whole window again.
+#[derive(Clone, Debug)]
+pub struct CheckpointStore {
+	/// The backend where the checkpoint of each chain is stored.
+	store: Arc<dyn StateStore>,
+}
+
+impl CheckpointStore {
+	/// Instantiates a new `CheckpointStore` that stores checkpoints in the given backend.
+	pub fn new(store: Arc<dyn StateStore>) -> Self {
+		Self { store }
+	}
+
+	/// Returns the checkpoint key of the given chain.
+	fn key(chain_id: ChainID) -> String {
+		format!("bootstrap-{}.checkpoint", chain_id)
+	}
+
+	/// Returns the last handled block of the given chain, if any checkpoint has been stored.
+	/// Unreadable checkpoints are treated as missing.
+	pub fn load(&self, chain_id: ChainID) -> Option<u64> {
+		let value = self.store.get(&Self::key(chain_id)).ok()??;
+		String::from_utf8(value).ok()?.trim().parse().ok()
+	}
+
+	/// Stores the last handled block of the given chain.
+	pub fn save(&self, chain_id: ChainID, block_number: u64) -> io::Result<()> {
+		self.store.put(&Self::key(chain_id), block_number.to_string().as_bytes())
+	}
+
+	/// Removes the checkpoint of the given chain.
+	pub fn clear(&self, chain_id: ChainID) -> io::Result<()> {
+		self.store.delete(&Self::key(chain_id))
+	}
+}
+
+/// Splits the given block range into chunks. If a checkpoint is given, the blocks up to the
+/// checkpoint are skipped.
+pub fn chunk_ranges(
+	from_block: u64,
+	to_block: u64,
+	chunk_size: u64,
+	checkpoint: Option<u64>,
+) -> Vec<(u64, u64)> {
+	let mut from_block = match checkpoint {
+		Some(checkpoint) => from_block.max(checkpoint.saturating_add(1)),
+		None => from_block,
+	};
+
+	let mut ranges = vec![];
+	while from_block <= to_block {
+		let chunk_to_block = std::cmp::min(from_block + chunk_size - 1, to_block);
+		ranges.push((from_block, chunk_to_block));
+		from_block = chunk_to_block + 1;
+	}
+	ranges
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::store::{FileStateStore, MemoryStateStore};
+
+	use super::*;
+
+	#[test]
+	fn splits_range_into_chunks() {
+		assert_eq!(chunk_ranges(1, 5, 2, None), vec![(1, 2), (3, 4), (5, 5)]);
+		assert_eq!(chunk_ranges(1, 5, 10, Some(0)), vec![(1, 5)]);
+		assert!(chunk_ranges(6, 5, 2, None).is_empty());
+	}
+
+	#[test]
+	fn resumes_from_last_persisted_chunk() {
+		let backend: Arc<dyn StateStore> = Arc::new(MemoryStateStore::default());
+		let store = CheckpointStore::new(backend.clone());
+		assert_eq!(store.load(1), None);
+
+		// the bootstrap is interrupted after handling the first chunk
+		let ranges = chunk_ranges(100, 105, 2, store.load(1));
+		let (_, chunk_to_block) = ranges[0];
+		store.save(1, chunk_to_block).unwrap();
+
+		// a restarted bootstrap skips the handled chunk, other chains are unaffected
+		let restarted = CheckpointStore::new(backend);
+		assert_eq!(restarted.load(1), Some(101));
+		assert_eq!(chunk_ranges(100, 105, 2, restarted.load(1)), vec![(102, 103), (104, 105)]);
+		assert_eq!(restarted.load(2), None);
+
+		// a checkpoint older than the bootstrap window keeps the window as is
+		assert_eq!(chunk_ranges(200, 201, 2, restarted.load(1)), vec![(200, 201)]);
+
+		// a completed bootstrap leaves no checkpoint behind
+		restarted.clear(1).unwrap();
+		assert_eq!(restarted.load(1), None);
+	}
+
+	#[test]
+	fn checkpoints_survive_restarts_on_file_store() {
+		let dir =
+			std::env::temp_dir().join(format!("bifrost-relayer-checkpoint-{}", std::process::id()));
+		let _ = std::fs::remove_dir_all(&dir);
+
+		CheckpointStore::new(Arc::new(FileStateStore::new(&dir))).save(1, 101).unwrap();
+		assert!(dir.join("bootstrap-1.checkpoint").exists());
+		assert_eq!(CheckpointStore::new(Arc::new(FileStateStore::new(&dir))).load(1), Some(101));
+
+		std::fs::remove_dir_all(&dir).unwrap();
+	}
+}
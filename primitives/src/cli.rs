@@ -2,7 +2,10 @@ use serde::Deserialize;
 use std::borrow::Cow;
 use std::fmt::Display;
 
-use crate::eth::ChainID;
+use crate::{
+	buffer::SheddingPolicy,
+	eth::{ChainID, ErrorSeverity, RelayerSetKind},
+};
 
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -51,14 +54,56 @@ pub struct RelayerConfig {
 	pub sentry_config: Option<SentryConfig>,
 	/// Prometheus config
 	pub prometheus_config: Option<PrometheusConfig>,
+	/// Statsd config (requires the `statsd` feature)
+	pub statsd_config: Option<StatsdConfig>,
+	/// Summary report config
+	pub report_config: Option<ReportConfig>,
+	/// Health endpoint config
+	pub health_config: Option<HealthConfig>,
+	/// Audit sink config
+	pub audit_config: Option<AuditConfig>,
+	/// Dead letter queue config
+	pub dead_letter_config: Option<DeadLetterConfig>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct SystemConfig {
-	/// The private key of the relayer.
-	pub private_key: String,
+	/// The private key of the relayer. Not required when `observer_mode` is enabled.
+	pub private_key: Option<String>,
+	/// If true, the relayer runs as a read-only observer. Events are detected, decoded, logged
+	/// and measured, but relayer selection is never checked and no transaction is ever signed
+	/// or submitted. No private key is required in this mode. (default: false)
+	pub observer_mode: Option<bool>,
 	/// Debug mode enabled if set to `true`.
 	pub debug_mode: Option<bool>,
+	/// The maximum number of rollbackable socket messages, and of socket events deferred while
+	/// relaying is paused, buffered in memory for each chain. (default: 1024)
+	pub max_buffered_events: Option<usize>,
+	/// The policy applied when the buffer is full. Every shed message is alerted as an error.
+	/// (default: RejectNewest)
+	pub shedding_policy: Option<SheddingPolicy>,
+	/// The signing priority of this relayer among the relayers operated on the same host.
+	/// (default: none)
+	pub signing_priority: Option<SigningPriorityConfig>,
+	/// The relayer addresses trusted to sign socket messages and relayer sets. A signature from
+	/// any other signer is alerted as an anomaly, and only the trusted relayers count towards the
+	/// minimum number of selected relayers. When not set, the selected relayer set fetched from
+	/// the chain is the only source of trust. (default: none)
+	pub trusted_relayers: Option<Vec<String>>,
+	/// The maximum time to drain the in-flight work (e.g. pending submissions) on shutdown in
+	/// seconds. Work still pending after the timeout is abandoned. (default: 60s)
+	pub shutdown_drain_timeout: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+/// Staggers the submissions of several relayer identities run by a single operator, so that they
+/// don't all submit the same relay at once. Each identity waits `index * stagger_interval` before
+/// the first try of its transactions.
+pub struct SigningPriorityConfig {
+	/// The index of this identity among the co-located relayers. `0` submits first.
+	pub index: u32,
+	/// The delay(ms) between the submissions of consecutive identities. (default: 3s)
+	pub stagger_interval: Option<u64>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -69,6 +114,18 @@ pub struct EVMProvider {
 	pub id: ChainID,
 	/// Endpoint provider
 	pub provider: String,
+	/// Fallback endpoint providers. Calls, fee estimations and transaction submissions will be
+	/// sent to the first healthy fallback while the primary `provider` is degraded. Only
+	/// transport errors and timeouts degrade an endpoint, reverts don't. (default: none)
+	pub fallback_providers: Option<Vec<String>>,
+	/// An endpoint is considered degraded when its average latency(ms) exceeds this threshold.
+	/// (default: 3s)
+	pub endpoint_latency_threshold: Option<u64>,
+	/// An endpoint is considered degraded when this number of consecutive requests fails.
+	/// (default: 3)
+	pub endpoint_error_threshold: Option<u32>,
+	/// A degraded endpoint will be retried after this amount of time(ms). (default: 60s)
+	pub endpoint_recovery_interval: Option<u64>,
 	/// The time interval(ms) used when to request a new block
 	pub call_interval: u64,
 	/// The number of confirmations required for a block to be processed.
@@ -93,11 +150,51 @@ pub struct EVMProvider {
 	/// If first relay transaction is stuck in mempool after waiting for this amount of time(ms),
 	/// ignore duplicate prevent logic. (default: 12s)
 	pub duplicate_confirm_delay: Option<u64>,
+	/// The maximum random delay(ms) given before the first try of relay transactions to this
+	/// chain. The actual delay is randomly chosen between zero and this value, so that relayers
+	/// won't submit the same relay at once. (default: 12s)
+	pub max_random_delay: Option<u64>,
 	/// The batch size (=block range) used when requesting `eth_getLogs()`. If increased the RPC
 	/// request ratio will be reduced, however event processing will be delayed regarded to the
 	/// configured batch size. Default size is set to 1, which means it will be requested on every
 	/// new block. (default: 1)
 	pub get_logs_batch_size: Option<u64>,
+	/// The block number that the socket contract has been deployed at. Events emitted before this
+	/// block will be ignored and bootstrapping won't request logs below this block. (default: 0)
+	pub deployment_block: Option<u64>,
+	/// The maximum age (in blocks) of an event compared to the latest block. Socket events older
+	/// than this will be discarded instead of being relayed. (default: none, never discarded)
+	pub max_event_age: Option<u64>,
+	/// The maximum number of blocks an imported block may be ahead of the latest block. A larger
+	/// gap implies a desynced view of the chain, so the latest block will be refreshed until the
+	/// gap closes before processing the block's events. (default: 64)
+	pub max_forward_gap: Option<u64>,
+	/// The minimum depth (in blocks) of a reorg of already processed blocks that requires the
+	/// affected range to be re-processed. The hash of each processed block is tracked and compared
+	/// against the canonical chain, which costs an extra RPC call per processed batch. Shallower
+	/// reorgs are only logged. On re-processing, the socket handler discards the messages it has
+	/// recently relayed (keyed by request chain, sequence and status). Other handlers may repeat
+	/// their submissions. (default: none, disabled)
+	pub deep_reorg_threshold: Option<u64>,
+	/// The revert reasons (case-insensitive) that imply another relayer's submission has already
+	/// changed the contract state. Rejected submissions with these reasons are treated as benign
+	/// conflicts and won't be retried nor reported as errors. An empty list treats every rejection
+	/// as a failure. (default: `["already processed", "already submitted"]`)
+	pub conflict_revert_reasons: Option<Vec<String>>,
+	/// Re-query a sent transaction that returned no receipt. Some providers don't return the
+	/// receipt of a mined transaction. If the receipt is still missing but the nonce of the
+	/// transaction has been consumed, the request won't be retried. Otherwise, it'll be retried
+	/// as usual. (default: false)
+	pub requery_missing_receipt: Option<bool>,
+	/// Only act on the explicitly allowed contracts and events of this chain. When disabled, every
+	/// contract and event known to the handlers is allowed. (default: false)
+	pub strict_event_filtering: Option<bool>,
+	/// The contract addresses allowed under strict event filtering.
+	/// ex) `["0xd551F33Ca8eCb0Be83d8799D9C68a368BA36Dd52"]` (default: none)
+	pub allowed_contracts: Option<Vec<String>>,
+	/// The event names allowed under strict event filtering. ex) `["Socket", "RoundUp"]`
+	/// (default: none)
+	pub allowed_events: Option<Vec<String>>,
 	/// Socket contract address
 	pub socket_address: String,
 	/// Authority contract address
@@ -134,12 +231,37 @@ impl Display for HandlerType {
 	}
 }
 
+impl HandlerType {
+	/// Returns the relayer set used for selection checks when `relayer_set` isn't configured.
+	/// Socket relays are verified against the current set, while RoundUp relays must be verified
+	/// against the initial set of the round.
+	pub fn default_relayer_set(&self) -> RelayerSetKind {
+		match *self {
+			HandlerType::Socket => RelayerSetKind::Current,
+			HandlerType::Roundup => RelayerSetKind::Initial,
+		}
+	}
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct HandlerConfig {
 	/// Handle type
 	pub handler_type: HandlerType,
 	/// Watch target list
 	pub watch_list: Vec<ChainID>,
+	/// The relayer set used to verify whether this relayer was selected at the event's round.
+	/// `Initial` verifies against the set snapshotted when the round started, `Current` against
+	/// the currently active set. (default: `Current` for Socket, `Initial` for Roundup)
+	pub relayer_set: Option<RelayerSetKind>,
+	/// The minimum number of selected relayers required to relay. Relays are paused with an alert
+	/// while the selected relayer set is smaller than this, and the paused events are deferred
+	/// until the set recovers. Only used by Socket handlers. (default: none)
+	pub min_selected_relayers: Option<u32>,
+	/// The severity of event decode errors while bootstrapping. `Warn` only logs the error, while
+	/// `Error` also captures it to Sentry. (default: `Warn`)
+	pub bootstrap_decode_error_severity: Option<ErrorSeverity>,
+	/// The severity of event decode errors while processing new blocks. (default: `Error`)
+	pub decode_error_severity: Option<ErrorSeverity>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -148,6 +270,13 @@ pub struct BootstrapConfig {
 	pub is_enabled: bool,
 	/// Round for bootstrap
 	pub round_offset: Option<u32>,
+	/// The maximum allowed difference between the latest rounds of `relayer_manager` and
+	/// `authority` before a warning is emitted. The rounds are only cross-checked when this is
+	/// set. (default: none)
+	pub round_drift_tolerance: Option<u32>,
+	/// The directory where the bootstrap checkpoint of each chain is flushed after every chunk.
+	/// An interrupted bootstrap resumes from the last flushed chunk. (default: none)
+	pub checkpoint_dir: Option<String>,
 }
 
 #[derive(Default, Debug, Clone, Deserialize)]
@@ -158,6 +287,9 @@ pub struct SentryConfig {
 	pub is_enabled: bool,
 	/// The DSN that tells Sentry where to send the events to.
 	pub dsn: String,
+	/// The maximum number of breadcrumbs attached to a capture. Breadcrumbs are scoped to each
+	/// processed event and cleared afterward. (default: 100)
+	pub max_breadcrumbs: Option<usize>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -173,3 +305,76 @@ pub struct PrometheusConfig {
 	/// Prometheus exporter TCP Port.
 	pub port: Option<u16>,
 }
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct StatsdConfig {
+	/// Exports the relayer metrics to a statsd agent.
+	///
+	/// Statsd exporter is disabled by default.
+	pub is_enabled: bool,
+	/// The host of the statsd agent. (default: 127.0.0.1)
+	pub host: Option<String>,
+	/// The UDP port of the statsd agent. (default: 8125)
+	pub port: Option<u16>,
+	/// The prefix prepended to every exported metric name. (default: none)
+	pub prefix: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeadLetterConfig {
+	/// Route the socket events that failed to be decoded to a dead letter queue, with the raw
+	/// log (block, event index and raw bytes) and the decode error, so that they can be
+	/// reprocessed or analyzed in bulk later.
+	///
+	/// Dead letter queue is disabled by default.
+	pub is_enabled: bool,
+	/// The directory where each dead letter is stored as a JSON file.
+	pub dir: String,
+	/// The maximum number of stored dead letters. (default: 1000)
+	pub capacity: Option<usize>,
+	/// The policy applied when the queue is full. Every shed letter is alerted as an error.
+	/// (default: RejectNewest)
+	pub shedding_policy: Option<SheddingPolicy>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AuditConfig {
+	/// Export every handler state transition (bootstrap start/complete, relay pause/resume,
+	/// selected/unselected) as a structured audit record with its timestamp and triggering reason.
+	///
+	/// Audit sink is disabled by default.
+	pub is_enabled: bool,
+	/// The JSON lines file that the records are appended to. (default: none)
+	pub path: Option<String>,
+	/// The webhook URL that each record is posted to as JSON. (default: none)
+	pub webhook_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct HealthConfig {
+	/// Expose the liveness(`/health/live`) and readiness(`/health/ready`) endpoints for
+	/// orchestrators. The relayer is ready once the bootstrap has been completed and every chain
+	/// is reachable, and live while every chain's block polling loop is making progress.
+	///
+	/// Health endpoint is disabled by default.
+	pub is_enabled: bool,
+	/// Expose the health endpoint on all interfaces. (default: local)
+	pub is_external: Option<bool>,
+	/// The health endpoint TCP port. (default: 8001)
+	pub port: Option<u16>,
+	/// A chain's block polling loop is considered stalled if it hasn't made progress within this
+	/// amount of time(seconds). (default: 300)
+	pub stall_threshold: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReportConfig {
+	/// The interval(minutes) that a summary report of the relayer metrics is written. The report
+	/// is disabled if set to 0.
+	pub interval: u64,
+	/// The file path that the latest report is written to. Previous reports are rotated to
+	/// `<path>.1`, `<path>.2`, and so on.
+	pub path: String,
+	/// The maximum number of rotated reports kept besides the latest one. (default: 24)
+	pub max_files: Option<usize>,
+}
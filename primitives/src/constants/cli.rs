@@ -4,6 +4,28 @@ pub const DEFAULT_BOOTSTRAP_ROUND_OFFSET: u32 = 3;
 /// The default port used for prometheus.
 pub const DEFAULT_PROMETHEUS_PORT: u16 = 8000;
 
+/// The default maximum number of breadcrumbs attached to a Sentry capture.
+pub const DEFAULT_SENTRY_MAX_BREADCRUMBS: usize = 100;
+
+/// The default port used for the health endpoint.
+pub const DEFAULT_HEALTH_PORT: u16 = 8001;
+
+/// The default threshold that a chain's block polling loop is considered stalled in seconds.
+/// (=300s)
+pub const DEFAULT_HEALTH_STALL_THRESHOLD_SEC: u64 = 300;
+
+/// The default refresh interval of the `status` subcommand in seconds. (=2s)
+pub const DEFAULT_STATUS_REFRESH_INTERVAL_SEC: u64 = 2;
+
+/// The default timeout to drain the in-flight work on shutdown in seconds. (=60s)
+pub const DEFAULT_SHUTDOWN_DRAIN_TIMEOUT_SEC: u64 = 60;
+
+/// The default host used for the statsd agent.
+pub const DEFAULT_STATSD_HOST: &str = "127.0.0.1";
+
+/// The default port used for the statsd agent.
+pub const DEFAULT_STATSD_PORT: u16 = 8125;
+
 /// The default batch size used for `eth_getLogs()`. (=1 block)
 pub const DEFAULT_GET_LOGS_BATCH_SIZE: u64 = 1;
 
@@ -16,6 +38,32 @@ pub const DEFAULT_ESCALATE_INTERVAL_SEC: u64 = 12;
 /// The default duplication confirm delay in milliseconds. (=12s)
 pub const DEFAULT_DUPLICATE_CONFIRM_DELAY_MS: u64 = 12_000;
 
+/// The default delay between the submissions of co-located relayers in milliseconds. (=3s)
+pub const DEFAULT_SIGNING_STAGGER_INTERVAL_MS: u64 = 3_000;
+
+/// The default maximum random delay given before relaying to external chains in milliseconds.
+/// (=12s)
+pub const DEFAULT_MAX_RANDOM_DELAY_MS: u64 = 12_000;
+
+/// The default average latency threshold of a degraded RPC endpoint in milliseconds. (=3s)
+pub const DEFAULT_ENDPOINT_LATENCY_THRESHOLD_MS: u64 = 3_000;
+
+/// The default consecutive error threshold of a degraded RPC endpoint. (=3 errors)
+pub const DEFAULT_ENDPOINT_ERROR_THRESHOLD: u32 = 3;
+
+/// The default interval that a degraded RPC endpoint will be retried in milliseconds. (=60s)
+pub const DEFAULT_ENDPOINT_RECOVERY_INTERVAL_MS: u64 = 60_000;
+
+/// The default maximum number of blocks an imported block may be ahead of the latest block.
+/// (=64 blocks)
+pub const DEFAULT_MAX_FORWARD_GAP: u64 = 64;
+
+/// The default maximum number of rotated summary reports. (=24 files)
+pub const DEFAULT_REPORT_MAX_FILES: usize = 24;
+
+/// The default maximum number of buffered events for each chain. (=1024 events)
+pub const DEFAULT_MAX_BUFFERED_EVENTS: usize = 1024;
+
 /// The default minimum priority fee in wei. (=0 wei)
 pub const DEFAULT_MIN_PRIORITY_FEE: u64 = 0;
 
@@ -37,8 +85,31 @@ pub const MAX_ESCALATE_INTERVAL_SEC: u64 = 60;
 /// The maximum duplication confirm delay allowed in milliseconds. (=60s)
 pub const MAX_DUPLICATE_CONFIRM_DELAY_MS: u64 = 60_000;
 
+/// The maximum random delay allowed before relaying to external chains in milliseconds. (=60s)
+pub const MAX_RANDOM_DELAY_MS: u64 = 60_000;
+
 /// The minimum batch size allowed for `eth_getLogs()`. (=1 block)
 pub const MIN_GET_LOGS_BATCH_SIZE: u64 = 1;
 
+/// The minimum average latency threshold allowed for a degraded RPC endpoint in milliseconds.
+/// (=100ms)
+pub const MIN_ENDPOINT_LATENCY_THRESHOLD_MS: u64 = 100;
+
+/// The maximum average latency threshold allowed for a degraded RPC endpoint in milliseconds.
+/// (=60s)
+pub const MAX_ENDPOINT_LATENCY_THRESHOLD_MS: u64 = 60_000;
+
+/// The minimum consecutive error threshold allowed for a degraded RPC endpoint. (=1 error)
+pub const MIN_ENDPOINT_ERROR_THRESHOLD: u32 = 1;
+
+/// The maximum consecutive error threshold allowed for a degraded RPC endpoint. (=100 errors)
+pub const MAX_ENDPOINT_ERROR_THRESHOLD: u32 = 100;
+
+/// The minimum interval allowed before retrying a degraded RPC endpoint in milliseconds. (=1s)
+pub const MIN_ENDPOINT_RECOVERY_INTERVAL_MS: u64 = 1_000;
+
+/// The maximum interval allowed before retrying a degraded RPC endpoint in milliseconds. (=1h)
+pub const MAX_ENDPOINT_RECOVERY_INTERVAL_MS: u64 = 3_600_000;
+
 /// The maximum round offset allowed for bootstrap. (=14 rounds)
 pub const MAX_BOOTSTRAP_ROUND_OFFSET: u32 = 14;
@@ -7,5 +7,32 @@ pub const ETHEREUM_BLOCK_TIME: u64 = 12u64;
 /// The block range chunk size for getLogs requests.
 pub const BOOTSTRAP_BLOCK_CHUNK_SIZE: u64 = 2000;
 
+/// The interval of the logs emitted while the bootstrap states are transitional. (=30s)
+pub const TRANSITIONAL_STATE_LOG_INTERVAL_MS: u64 = 30_000;
+
+/// The interval of the logs emitted on malformed event stream items. (=30s)
+pub const MALFORMED_STREAM_ITEM_LOG_INTERVAL_MS: u64 = 30_000;
+
+/// The interval of the bootstrap progress logs. (=30s)
+pub const BOOTSTRAP_PROGRESS_LOG_INTERVAL_MS: u64 = 30_000;
+
+/// The sliding window that the bootstrap scan rate is measured over. (=60s)
+pub const BOOTSTRAP_RATE_WINDOW_MS: u64 = 60_000;
+
+/// The default maximum number of stored dead letters.
+pub const DEFAULT_DEAD_LETTER_CAPACITY: usize = 1000;
+
+/// The maximum number of recent errors kept in the status snapshot.
+pub const STATUS_RECENT_ERRORS_CAPACITY: usize = 10;
+
+/// The maximum number of processed blocks tracked for reorg detection.
+pub const REORG_TRACKING_CAPACITY: usize = 128;
+
+/// The maximum number of latest block refreshes while an imported block is far ahead of it.
+pub const MAX_RECONCILE_ATTEMPTS: u32 = 20;
+
+/// The maximum number of relayed socket messages remembered to discard re-processed events.
+pub const RELAYED_SOCKET_TRACKING_CAPACITY: usize = 4096;
+
 /// The block offset used to measure the average block time at bootstrap.
 pub const BOOTSTRAP_BLOCK_OFFSET: u32 = 100;
@@ -7,6 +7,15 @@ pub const INVALID_CONTRACT_ADDRESS: &str =
 pub const INVALID_PRIVATE_KEY: &str =
 	"Invalid private key provided. Please check your relayer's private key.";
 
+pub const MISSING_PRIVATE_KEY: &str =
+	"No private key provided. Please set your relayer's private key or enable `observer_mode`.";
+
+pub const SUBMISSION_WITHOUT_PRIVATE_KEY: &str =
+	"Attempted to sign or submit without a configured private key. Observers must never sign or submit.";
+
+pub const UNREGISTERED_RELAYER: &str =
+	"The relayer address derived from the private key isn't registered on-chain. Please check your relayer's private key.";
+
 pub const INVALID_PROVIDER_URL: &str =
 	"Invalid provider URL provided. Please check your provider's URL.";
 
@@ -19,6 +28,15 @@ pub const INVALID_BIFROST_NATIVENESS: &str =
 pub const INVALID_PERIODIC_SCHEDULE: &str =
 	"Invalid periodic schedule format provided. Please check your schedule format.";
 
+pub const INVALID_STATSD_ENDPOINT: &str =
+	"Invalid statsd endpoint provided. Please check your statsd_config's host and port.";
+
+pub const DUPLICATE_CHAIN_ID: &str =
+	"Duplicate chain ID provided. Please check your evm_providers' `id` fields.";
+
+pub const DUPLICATE_PROVIDER_URL: &str =
+	"Duplicate provider URL provided. Please check your evm_providers' `provider` fields.";
+
 pub const INVALID_CONFIG_FILE_PATH: &str =
 	"Invalid config.yaml file path provided. Please check your file path.";
 
@@ -39,3 +57,9 @@ pub const PROVIDER_INTERNAL_ERROR: &str =
 
 pub const PARAMETER_OUT_OF_RANGE: &str =
 	"An invalid parameter is out of range. Please check your configuration file.";
+
+pub const INVALID_TRUSTED_RELAYER_ADDRESS: &str =
+	"Invalid trusted relayer address provided. Please check your system's `trusted_relayers` field.";
+
+pub const MISSING_NATIVE_AUTHORITY_CONTRACT: &str =
+	"The native chain requires an authority contract. Please check your native evm_provider's `authority_address` field.";
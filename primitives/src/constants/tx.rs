@@ -15,3 +15,8 @@ pub const MAX_FEE_COEFFICIENT: u64 = 2;
 
 /// The coefficient that will be multipled on the max priority fee.
 pub const MAX_PRIORITY_FEE_COEFFICIENT: u64 = 2;
+
+/// The default revert reasons that imply another relayer's submission has already changed the
+/// contract state. Broader reasons (e.g. `invalid round`) may hide genuine failures, so those are
+/// left to be configured explicitly.
+pub const DEFAULT_CONFLICT_REVERT_REASONS: [&str; 2] = ["already processed", "already submitted"];
@@ -5,3 +5,34 @@ abigen!(
 	"../abi/abi.authority.merged.json",
 	event_derives(serde::Deserialize, serde::Serialize)
 );
+
+impl RoundMetaData {
+	/// Verifies whether the critical round values are zeroed. A zeroed round implies that the
+	/// authority contract isn't deployed at the configured address.
+	pub fn is_zeroed(&self) -> bool {
+		self.current_round_index.is_zero() || self.round_length.is_zero()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use ethers::types::U256;
+
+	use super::*;
+
+	fn round_info(current_round_index: u64, round_length: u64) -> RoundMetaData {
+		RoundMetaData {
+			current_round_index: U256::from(current_round_index),
+			round_length: U256::from(round_length),
+			..Default::default()
+		}
+	}
+
+	#[test]
+	fn zero_round_is_zeroed() {
+		assert!(RoundMetaData::default().is_zeroed());
+		assert!(round_info(0, 600).is_zeroed());
+		assert!(round_info(42, 0).is_zeroed());
+		assert!(!round_info(42, 600).is_zeroed());
+	}
+}
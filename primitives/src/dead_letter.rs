@@ -0,0 +1,160 @@
+use std::{
+	io,
+	sync::{Arc, Mutex},
+};
+
+use ethers::types::Log;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	buffer::{BufferInsertion, SheddingPolicy},
+	eth::ChainID,
+	store::StateStore,
+};
+
+/// The key prefix of the dead letters.
+const KEY_PREFIX: &str = "dead-letter-";
+
+/// An event that failed to be decoded, kept with the context required to reprocess it.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DeadLetter {
+	/// The chain ID that the event has been emitted on.
+	pub chain_id: ChainID,
+	/// The raw log of the event. It contains the block, the event index and the raw bytes.
+	pub log: Log,
+	/// The decode error.
+	pub error: String,
+	/// The flag whether the event has been processed on bootstrap.
+	pub is_bootstrap: bool,
+}
+
+/// Persists the events that failed to be decoded, so that they can be reprocessed or analyzed in
+/// bulk later. The queue never holds more than `capacity` letters. Once full, the configured
+/// `SheddingPolicy` decides which letter will be shed.
+#[derive(Debug)]
+pub struct DeadLetterQueue {
+	/// The backend where the letters are stored.
+	store: Arc<dyn StateStore>,
+	/// The maximum number of stored letters.
+	capacity: usize,
+	/// The policy applied when the queue is full.
+	policy: SheddingPolicy,
+	/// The lock that serializes the pushes of every chain.
+	lock: Mutex<()>,
+}
+
+impl DeadLetterQueue {
+	/// Instantiates a new `DeadLetterQueue` that stores letters in the given backend.
+	pub fn new(store: Arc<dyn StateStore>, capacity: usize, policy: SheddingPolicy) -> Self {
+		Self { store, capacity, policy, lock: Mutex::new(()) }
+	}
+
+	/// Returns the key of the letter at the given sequence. Keys are zero-padded so that they're
+	/// ordered by insertion.
+	fn key(sequence: u64) -> String {
+		format!("{}{:020}", KEY_PREFIX, sequence)
+	}
+
+	/// Stores the given letter. Returns the key of the letter dropped to make room, if any.
+	pub fn push(&self, letter: &DeadLetter) -> io::Result<BufferInsertion<String>> {
+		let _guard = self.lock.lock().unwrap();
+		let keys: Vec<String> =
+			self.store.scan(KEY_PREFIX)?.into_iter().map(|(key, _)| key).collect();
+
+		let mut insertion = BufferInsertion::Inserted;
+		if keys.len() >= self.capacity {
+			match self.policy {
+				SheddingPolicy::RejectNewest => return Ok(BufferInsertion::Rejected),
+				SheddingPolicy::DropOldest => match keys.first() {
+					Some(oldest) => {
+						self.store.delete(oldest)?;
+						insertion = BufferInsertion::DroppedOldest(oldest.clone());
+					},
+					// a zero capacity queue can't hold anything
+					None => return Ok(BufferInsertion::Rejected),
+				},
+			}
+		}
+
+		let sequence = keys
+			.last()
+			.and_then(|key| key.trim_start_matches(KEY_PREFIX).parse::<u64>().ok())
+			.map_or(0, |sequence| sequence + 1);
+		self.store.put(&Self::key(sequence), &serde_json::to_vec(letter)?)?;
+		Ok(insertion)
+	}
+
+	/// Returns every stored letter, oldest first. Unreadable letters are skipped.
+	pub fn letters(&self) -> io::Result<Vec<DeadLetter>> {
+		Ok(self
+			.store
+			.scan(KEY_PREFIX)?
+			.into_iter()
+			.filter_map(|(_, value)| serde_json::from_slice(&value).ok())
+			.collect())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use ethers::types::{Bytes, U256, U64};
+
+	use crate::store::MemoryStateStore;
+
+	use super::*;
+
+	fn letter(block_number: u64) -> DeadLetter {
+		DeadLetter {
+			chain_id: 3068,
+			log: Log {
+				block_number: Some(U64::from(block_number)),
+				log_index: Some(U256::from(2)),
+				data: Bytes::from(vec![0xde, 0xad]),
+				..Default::default()
+			},
+			error: String::from("Invalid data"),
+			is_bootstrap: false,
+		}
+	}
+
+	#[test]
+	fn letters_keep_full_context() {
+		let queue = DeadLetterQueue::new(
+			Arc::new(MemoryStateStore::default()),
+			10,
+			SheddingPolicy::DropOldest,
+		);
+		queue.push(&letter(100)).unwrap();
+
+		let letters = queue.letters().unwrap();
+		assert_eq!(letters, vec![letter(100)]);
+		assert_eq!(letters[0].log.data, Bytes::from(vec![0xde, 0xad]));
+		assert_eq!(letters[0].log.log_index, Some(U256::from(2)));
+	}
+
+	#[test]
+	fn full_queue_sheds_by_policy() {
+		let queue = DeadLetterQueue::new(
+			Arc::new(MemoryStateStore::default()),
+			2,
+			SheddingPolicy::DropOldest,
+		);
+		for block_number in [1, 2] {
+			assert_eq!(queue.push(&letter(block_number)).unwrap(), BufferInsertion::Inserted);
+		}
+		assert_eq!(
+			queue.push(&letter(3)).unwrap(),
+			BufferInsertion::DroppedOldest(DeadLetterQueue::key(0))
+		);
+		assert_eq!(queue.letters().unwrap(), vec![letter(2), letter(3)]);
+
+		let queue = DeadLetterQueue::new(
+			Arc::new(MemoryStateStore::default()),
+			1,
+			SheddingPolicy::RejectNewest,
+		);
+		queue.push(&letter(1)).unwrap();
+		assert_eq!(queue.push(&letter(2)).unwrap(), BufferInsertion::Rejected);
+		assert_eq!(queue.letters().unwrap(), vec![letter(1)]);
+	}
+}
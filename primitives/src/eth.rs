@@ -1,15 +1,26 @@
-use std::{str::FromStr, sync::Arc};
+use std::{
+	collections::{BTreeMap, BTreeSet, VecDeque},
+	fmt::{Display, Formatter},
+	str::FromStr,
+	sync::Arc,
+};
 
 use ethers::{
 	providers::{JsonRpcClient, Provider},
-	types::{Address, Signature, TransactionRequest, H160, U64},
+	types::{Address, Signature, TransactionRequest, H160, H256, U256, U64},
 };
+use serde::Deserialize;
 
 use crate::{
-	constants::errors::INVALID_CONTRACT_ADDRESS,
+	constants::{
+		cli::DEFAULT_MAX_FORWARD_GAP, errors::INVALID_CONTRACT_ADDRESS,
+		tx::DEFAULT_CONFLICT_REVERT_REASONS,
+	},
 	contracts::{
-		authority::AuthorityContract, chainlink_aggregator::ChainlinkContract,
-		relayer_manager::RelayerManagerContract, socket::SocketContract,
+		authority::AuthorityContract,
+		chainlink_aggregator::ChainlinkContract,
+		relayer_manager::RelayerManagerContract,
+		socket::{get_asset_oids, SocketContract},
 	},
 };
 
@@ -32,6 +43,24 @@ pub struct ProviderMetadata {
 	pub if_destination_chain: RelayDirection,
 	/// The flag whether the chain is Bifrost(native) or an external chain.
 	pub is_native: bool,
+	/// The block number that the socket contract has been deployed at.
+	pub deployment_block: U64,
+	/// The maximum age (in blocks) of an event to be processed.
+	pub max_event_age: Option<U64>,
+	/// The maximum number of blocks an imported block may be ahead of the latest block.
+	pub max_forward_gap: U64,
+	/// The minimum depth (in blocks) of a reorg of processed blocks that requires re-processing.
+	/// Reorgs are only detected when this is set.
+	pub deep_reorg_threshold: Option<U64>,
+	/// The revert reasons that imply another relayer's submission has already changed the
+	/// contract state.
+	pub conflict_revert_reasons: Vec<String>,
+	/// The flag whether a sent transaction that returned no receipt should be re-queried.
+	pub requery_missing_receipt: bool,
+	/// The relayer addresses trusted to sign. Every signer is trusted if this is `None`.
+	pub trusted_signers: Option<BTreeSet<Address>>,
+	/// The filter of the contracts and events to act on.
+	pub event_filter: EventFilter,
 }
 
 impl ProviderMetadata {
@@ -54,8 +83,143 @@ impl ProviderMetadata {
 				true => RelayDirection::Inbound,
 				false => RelayDirection::Outbound,
 			},
+			deployment_block: U64::default(),
+			max_event_age: None,
+			max_forward_gap: U64::from(DEFAULT_MAX_FORWARD_GAP),
+			deep_reorg_threshold: None,
+			conflict_revert_reasons: DEFAULT_CONFLICT_REVERT_REASONS
+				.iter()
+				.map(|reason| reason.to_string())
+				.collect(),
+			requery_missing_receipt: false,
+			trusted_signers: None,
+			event_filter: EventFilter::default(),
+		}
+	}
+
+	/// Sets the bounds of the events to be processed. Events emitted before `deployment_block`
+	/// or older than `max_event_age` blocks will be ignored.
+	pub fn with_event_bounds(
+		mut self,
+		deployment_block: Option<u64>,
+		max_event_age: Option<u64>,
+	) -> Self {
+		self.deployment_block = U64::from(deployment_block.unwrap_or_default());
+		self.max_event_age = max_event_age.map(U64::from);
+		self
+	}
+
+	/// Sets the maximum number of blocks an imported block may be ahead of the latest block.
+	pub fn with_max_forward_gap(mut self, max_forward_gap: u64) -> Self {
+		self.max_forward_gap = U64::from(max_forward_gap);
+		self
+	}
+
+	/// Sets the minimum depth of a reorg of processed blocks that requires re-processing.
+	pub fn with_deep_reorg_threshold(mut self, deep_reorg_threshold: Option<u64>) -> Self {
+		self.deep_reorg_threshold = deep_reorg_threshold.map(U64::from);
+		self
+	}
+
+	/// Sets the revert reasons that imply a conflict with another relayer's submission. The
+	/// defaults are kept if not given.
+	pub fn with_conflict_revert_reasons(mut self, reasons: Option<Vec<String>>) -> Self {
+		if let Some(reasons) = reasons {
+			self.conflict_revert_reasons = reasons;
+		}
+		self
+	}
+
+	/// Sets whether a sent transaction that returned no receipt should be re-queried.
+	pub fn with_requery_missing_receipt(mut self, requery_missing_receipt: bool) -> Self {
+		self.requery_missing_receipt = requery_missing_receipt;
+		self
+	}
+
+	/// Sets the relayer addresses trusted to sign.
+	pub fn with_trusted_signers(mut self, trusted_signers: Option<Vec<Address>>) -> Self {
+		self.trusted_signers = trusted_signers.map(|signers| signers.into_iter().collect());
+		self
+	}
+
+	/// Returns `true` if the given signer is trusted. Every signer is trusted if no trusted set
+	/// has been configured.
+	pub fn is_trusted_signer(&self, signer: &Address) -> bool {
+		match &self.trusted_signers {
+			Some(trusted_signers) => trusted_signers.contains(signer),
+			None => true,
 		}
 	}
+
+	/// Returns the signers of the given signatures that aren't trusted, in signature order.
+	pub fn untrusted_signers(&self, signatures: &[RecoveredSignature]) -> Vec<Address> {
+		signatures
+			.iter()
+			.filter(|signature| !self.is_trusted_signer(&signature.signer))
+			.map(|signature| signature.signer)
+			.collect()
+	}
+
+	/// Sets the filter of the contracts and events to act on.
+	pub fn with_event_filter(mut self, event_filter: EventFilter) -> Self {
+		self.event_filter = event_filter;
+		self
+	}
+
+	/// Verifies whether the given block is prior to the socket contract deployment.
+	pub fn is_before_deployment(&self, block_number: U64) -> bool {
+		block_number < self.deployment_block
+	}
+
+	/// Verifies whether the event emitted at the given block is older than the maximum event age.
+	pub fn is_stale_event(&self, block_number: U64, latest_block: U64) -> bool {
+		match self.max_event_age {
+			Some(max_event_age) => latest_block.saturating_sub(block_number) > max_event_age,
+			None => false,
+		}
+	}
+
+	/// Verifies whether the given block is further ahead of the latest block than the maximum
+	/// forward gap, which implies that the local view of the chain is desynced.
+	pub fn is_far_ahead(&self, block_number: U64, latest_block: U64) -> bool {
+		block_number.saturating_sub(latest_block) > self.max_forward_gap
+	}
+}
+
+#[derive(Clone, Debug, Default)]
+/// The allowlist of the contracts and events that the handlers act on. Without strict filtering,
+/// every contract and event known to the handler is allowed. With strict filtering, only the
+/// explicitly allowed ones pass, and an empty allowlist denies everything.
+pub struct EventFilter {
+	/// The flag whether strict(=default-deny) filtering is enabled.
+	pub is_strict: bool,
+	/// The allowed contract addresses.
+	pub contracts: Vec<Address>,
+	/// The allowed event names. ex) `Socket`, `RoundUp`
+	pub events: Vec<String>,
+}
+
+impl EventFilter {
+	pub fn new(is_strict: bool, contracts: Vec<String>, events: Vec<String>) -> Self {
+		Self {
+			is_strict,
+			contracts: contracts
+				.iter()
+				.map(|address| H160::from_str(address).expect(INVALID_CONTRACT_ADDRESS))
+				.collect(),
+			events,
+		}
+	}
+
+	/// Verifies whether the given contract is allowed.
+	pub fn allows_contract(&self, address: &Address) -> bool {
+		!self.is_strict || self.contracts.contains(address)
+	}
+
+	/// Verifies whether the event of the given name is allowed.
+	pub fn allows_event(&self, name: &str) -> bool {
+		!self.is_strict || self.events.iter().any(|event| event == name)
+	}
 }
 
 pub struct AggregatorContracts<T> {
@@ -231,6 +395,270 @@ impl From<SocketEventStatus> for u8 {
 	}
 }
 
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+/// The relayer set that is used to verify whether a relayer was selected at a certain round. This
+/// is passed as the `is_initial` argument of the `RelayerManager` contract's selection queries (e.g.
+/// `is_previous_selected_relayer(round, relayer, is_initial)`).
+pub enum RelayerSetKind {
+	/// The relayer set snapshotted when the round started. (`is_initial = true`)
+	Initial,
+	/// The relayer set that is currently active in the round. This may differ from the initial
+	/// set if relayers joined or left during the round. (`is_initial = false`)
+	Current,
+}
+
+impl RelayerSetKind {
+	/// Returns the `is_initial` argument used on the `RelayerManager` contract.
+	pub fn is_initial(&self) -> bool {
+		matches!(self, RelayerSetKind::Initial)
+	}
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+/// The severity used to report a recoverable error.
+pub enum ErrorSeverity {
+	/// The error is logged at the warn level.
+	Warn,
+	/// The error is logged at the error level and captured to Sentry.
+	Error,
+}
+
+#[derive(Clone, Copy, Debug)]
+/// The severities of event decode errors. Decode errors while bootstrapping (re-scanning old
+/// events) are less alarming than the ones while processing new blocks.
+pub struct DecodeErrorSeverity {
+	/// The severity of decode errors while bootstrapping.
+	bootstrap: ErrorSeverity,
+	/// The severity of decode errors while processing new blocks.
+	live: ErrorSeverity,
+}
+
+impl DecodeErrorSeverity {
+	/// Instantiates a new `DecodeErrorSeverity`. Bootstrap errors are reported as `Warn` and live
+	/// errors as `Error` unless configured.
+	pub fn new(bootstrap: Option<ErrorSeverity>, live: Option<ErrorSeverity>) -> Self {
+		Self {
+			bootstrap: bootstrap.unwrap_or(ErrorSeverity::Warn),
+			live: live.unwrap_or(ErrorSeverity::Error),
+		}
+	}
+
+	/// Returns the severity of a decode error.
+	pub fn of(&self, is_bootstrap: bool) -> ErrorSeverity {
+		match is_bootstrap {
+			true => self.bootstrap,
+			false => self.live,
+		}
+	}
+}
+
+#[derive(Clone, Debug, Default)]
+/// Tracks the latest observed selection result of the relayer to detect when it enters or leaves
+/// the selected relayer set. Results of older rounds (e.g. on bootstrap) are ignored.
+pub struct SelectionTracker {
+	/// The latest observed round and whether the relayer was selected at that round.
+	latest: Option<(U256, bool)>,
+}
+
+impl SelectionTracker {
+	/// Observes the selection result of the given round. Returns the new selection state if it
+	/// has been changed since the previous round.
+	pub fn observe(&mut self, round: U256, is_selected: bool) -> Option<bool> {
+		match self.latest {
+			Some((latest_round, _)) if round < latest_round => None,
+			Some((_, was_selected)) => {
+				self.latest = Some((round, is_selected));
+				(was_selected != is_selected).then_some(is_selected)
+			},
+			None => {
+				self.latest = Some((round, is_selected));
+				None
+			},
+		}
+	}
+
+	/// Returns the latest observed round, if any.
+	pub fn latest_round(&self) -> Option<U256> {
+		self.latest.map(|(round, _)| round)
+	}
+}
+
+/// The identity of a relayed socket message. <request chain, sequence, status>
+pub type SocketRelayKey = (ChainID, u128, u8);
+
+#[derive(Clone, Debug)]
+/// Remembers the latest relayed socket messages, so that an event re-processed after a deep reorg
+/// rewind won't be submitted twice. Only the latest `capacity` messages are remembered.
+pub struct RelayedSocketMessages {
+	/// The remembered messages.
+	keys: BTreeSet<SocketRelayKey>,
+	/// The remembered messages in the order they were relayed, oldest first.
+	order: VecDeque<SocketRelayKey>,
+	/// The maximum number of remembered messages.
+	capacity: usize,
+}
+
+impl RelayedSocketMessages {
+	/// Instantiates a new `RelayedSocketMessages` instance.
+	pub fn new(capacity: usize) -> Self {
+		Self { keys: BTreeSet::new(), order: VecDeque::new(), capacity }
+	}
+
+	/// Returns `true` if the given message has been relayed.
+	pub fn contains(&self, key: &SocketRelayKey) -> bool {
+		self.keys.contains(key)
+	}
+
+	/// Remembers the given message as relayed. Returns `false` if it has already been relayed.
+	pub fn insert(&mut self, key: SocketRelayKey) -> bool {
+		if !self.keys.insert(key) {
+			return false;
+		}
+		self.order.push_back(key);
+		if self.order.len() > self.capacity {
+			if let Some(oldest) = self.order.pop_front() {
+				self.keys.remove(&oldest);
+			}
+		}
+		true
+	}
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+/// The outcome of a socket event processed by the relayer.
+pub enum RelayOutcome {
+	/// The event has been relayed.
+	Relayed,
+	/// The event has been skipped since this relayer is an observer.
+	Observer,
+	/// The event has been skipped since this relayer wasn't selected at the event's round.
+	Unselected,
+	/// The event has been skipped since its protocol sequence has already ended.
+	SequenceEnded,
+}
+
+impl RelayOutcome {
+	/// Returns the name of the outcome.
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			RelayOutcome::Relayed => "relayed",
+			RelayOutcome::Observer => "observer",
+			RelayOutcome::Unselected => "unselected",
+			RelayOutcome::SequenceEnded => "sequence_ended",
+		}
+	}
+
+	/// Returns whether the relayer was selected at the event's round, if it has been checked.
+	fn is_selected(&self) -> Option<bool> {
+		match self {
+			RelayOutcome::Relayed | RelayOutcome::SequenceEnded => Some(true),
+			RelayOutcome::Unselected => Some(false),
+			RelayOutcome::Observer => None,
+		}
+	}
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// The summary of the socket events processed within a single round.
+pub struct RoundStats {
+	/// The round index.
+	pub round: U256,
+	/// The number of socket events seen.
+	pub seen: u64,
+	/// The number of socket events relayed.
+	pub relayed: u64,
+	/// The number of socket events skipped by each reason.
+	pub skipped: BTreeMap<RelayOutcome, u64>,
+	/// The total amount of the socket events seen, by their asset. <asset_oid, amount>
+	pub volume: BTreeMap<H256, U256>,
+	/// Whether the relayer was selected at this round. `None` if it hasn't been checked.
+	pub is_selected: Option<bool>,
+}
+
+impl RoundStats {
+	/// Records a socket event of this round with its asset, amount and outcome.
+	fn record(&mut self, asset: H256, amount: U256, outcome: RelayOutcome) {
+		self.seen += 1;
+		let volume = self.volume.entry(asset).or_default();
+		*volume = volume.saturating_add(amount);
+		match outcome {
+			RelayOutcome::Relayed => self.relayed += 1,
+			_ => *self.skipped.entry(outcome).or_default() += 1,
+		}
+		if let Some(is_selected) = outcome.is_selected() {
+			self.is_selected = Some(is_selected);
+		}
+	}
+}
+
+impl Display for RoundStats {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		let skipped: Vec<String> = self
+			.skipped
+			.iter()
+			.map(|(outcome, count)| format!("{}: {}", outcome.as_str(), count))
+			.collect();
+		let asset_oids = get_asset_oids();
+		let volume: Vec<String> = self
+			.volume
+			.iter()
+			.map(|(asset, amount)| match asset_oids.iter().find(|(_symbol, oid)| *oid == asset) {
+				Some((symbol, _oid)) => format!("{}: {}", symbol, amount),
+				None => format!("{:?}: {}", asset, amount),
+			})
+			.collect();
+		write!(
+			f,
+			"round({}): seen({}), relayed({}), skipped({}), volume({}), {}",
+			self.round,
+			self.seen,
+			self.relayed,
+			if skipped.is_empty() { String::from("-") } else { skipped.join(", ") },
+			if volume.is_empty() { String::from("-") } else { volume.join(", ") },
+			match self.is_selected {
+				Some(true) => "selected",
+				Some(false) => "unselected",
+				None => "selection unknown",
+			},
+		)
+	}
+}
+
+#[derive(Clone, Debug, Default)]
+/// Aggregates the `RoundStats` of the latest observed round. Events of older rounds (e.g. relayed
+/// late) are counted in the latest round, which is when they have been observed.
+pub struct RoundStatsTracker {
+	/// The stats of the latest observed round.
+	current: Option<RoundStats>,
+}
+
+impl RoundStatsTracker {
+	/// Starts the given round if it's newer than the latest observed round. Returns the stats of
+	/// the finished round.
+	pub fn advance(&mut self, round: U256) -> Option<RoundStats> {
+		match &self.current {
+			Some(current) if round <= current.round => None,
+			_ => self.current.replace(RoundStats { round, ..Default::default() }),
+		}
+	}
+
+	/// Records a socket event of the given round with its asset, amount and outcome. Returns the
+	/// stats of the finished round if the observed round has advanced.
+	pub fn record(
+		&mut self,
+		round: U256,
+		asset: H256,
+		amount: U256,
+		outcome: RelayOutcome,
+	) -> Option<RoundStats> {
+		let finished = self.advance(round);
+		self.current
+			.get_or_insert_with(|| RoundStats { round, ..Default::default() })
+			.record(asset, amount, outcome);
+		finished
+	}
+}
+
 #[derive(Clone, Copy, Debug)]
 /// The CCCP protocols relay direction.
 pub enum RelayDirection {
@@ -286,3 +714,195 @@ impl BuiltRelayTransaction {
 		Self { tx_request, is_external }
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn events_before_deployment_block_are_detected() {
+		let metadata = ProviderMetadata::new(String::from("ethereum"), 1, 12, 3000, 1, false)
+			.with_event_bounds(Some(100), None);
+
+		assert!(metadata.is_before_deployment(U64::from(99)));
+		assert!(!metadata.is_before_deployment(U64::from(100)));
+		assert!(!metadata.is_before_deployment(U64::from(101)));
+
+		let metadata = ProviderMetadata::new(String::from("ethereum"), 1, 12, 3000, 1, false)
+			.with_event_bounds(None, None);
+		assert!(!metadata.is_before_deployment(U64::zero()));
+	}
+
+	#[test]
+	fn only_configured_signers_are_trusted() {
+		let (trusted, untrusted) = (Address::from_low_u64_be(1), Address::from_low_u64_be(2));
+		let signatures = vec![
+			RecoveredSignature::new(
+				0,
+				Signature { r: U256::one(), s: U256::one(), v: 27 },
+				trusted,
+			),
+			RecoveredSignature::new(
+				1,
+				Signature { r: U256::one(), s: U256::one(), v: 28 },
+				untrusted,
+			),
+		];
+
+		let metadata = ProviderMetadata::new(String::from("bifrost"), 3068, 0, 3000, 1, true)
+			.with_trusted_signers(Some(vec![trusted]));
+		assert_eq!(metadata.untrusted_signers(&signatures), vec![untrusted]);
+
+		let metadata = ProviderMetadata::new(String::from("bifrost"), 3068, 0, 3000, 1, true);
+		assert!(metadata.untrusted_signers(&signatures).is_empty());
+	}
+
+	#[test]
+	fn events_over_max_age_are_stale() {
+		let metadata = ProviderMetadata::new(String::from("ethereum"), 1, 12, 3000, 1, false)
+			.with_event_bounds(None, Some(100));
+		let latest_block = U64::from(1_000);
+
+		assert!(metadata.is_stale_event(U64::from(899), latest_block));
+		assert!(!metadata.is_stale_event(U64::from(900), latest_block));
+		assert!(!metadata.is_stale_event(U64::from(1_000), latest_block));
+
+		let metadata = ProviderMetadata::new(String::from("ethereum"), 1, 12, 3000, 1, false)
+			.with_event_bounds(None, None);
+		assert!(!metadata.is_stale_event(U64::zero(), latest_block));
+	}
+
+	#[test]
+	fn large_forward_gap_is_far_ahead() {
+		let metadata = ProviderMetadata::new(String::from("ethereum"), 1, 12, 3000, 1, false)
+			.with_max_forward_gap(10);
+		let latest_block = U64::from(1_000);
+
+		assert!(metadata.is_far_ahead(U64::from(1_011), latest_block));
+		assert!(metadata.is_far_ahead(U64::from(50_000), latest_block));
+		assert!(!metadata.is_far_ahead(U64::from(1_010), latest_block));
+		assert!(!metadata.is_far_ahead(U64::from(900), latest_block));
+	}
+
+	#[test]
+	fn decode_errors_are_louder_on_live_processing() {
+		let severity = DecodeErrorSeverity::new(None, None);
+		assert_eq!(severity.of(true), ErrorSeverity::Warn);
+		assert_eq!(severity.of(false), ErrorSeverity::Error);
+
+		let severity = DecodeErrorSeverity::new(Some(ErrorSeverity::Error), None);
+		assert_eq!(severity.of(true), ErrorSeverity::Error);
+		assert_eq!(severity.of(false), ErrorSeverity::Error);
+	}
+
+	#[test]
+	fn event_filter_allows_everything_unless_strict() {
+		let filter = EventFilter::default();
+		assert!(filter.allows_contract(&Address::random()));
+		assert!(filter.allows_event("Socket"));
+
+		// non-strict filters ignore the allowlist
+		let filter = EventFilter::new(false, vec![], vec![String::from("RoundUp")]);
+		assert!(filter.allows_contract(&Address::random()));
+		assert!(filter.allows_event("Socket"));
+	}
+
+	#[test]
+	fn strict_event_filter_only_allows_allowlisted() {
+		let socket = "0xd551F33Ca8eCb0Be83d8799D9C68a368BA36Dd52";
+		let filter = EventFilter::new(true, vec![socket.to_string()], vec![String::from("Socket")]);
+
+		assert!(filter.allows_contract(&H160::from_str(socket).unwrap()));
+		assert!(!filter.allows_contract(&Address::random()));
+		assert!(filter.allows_event("Socket"));
+		assert!(!filter.allows_event("RoundUp"));
+
+		// an empty allowlist denies everything
+		let filter = EventFilter::new(true, vec![], vec![]);
+		assert!(!filter.allows_contract(&H160::from_str(socket).unwrap()));
+		assert!(!filter.allows_event("Socket"));
+	}
+
+	#[test]
+	fn selection_transitions_are_detected_across_rounds() {
+		let mut tracker = SelectionTracker::default();
+
+		// the first observation has nothing to compare with
+		assert_eq!(tracker.observe(U256::from(10), true), None);
+		assert_eq!(tracker.observe(U256::from(10), true), None);
+		assert_eq!(tracker.observe(U256::from(11), false), Some(false));
+		// older rounds don't affect the state
+		assert_eq!(tracker.observe(U256::from(9), true), None);
+		assert_eq!(tracker.latest_round(), Some(U256::from(11)));
+		assert_eq!(tracker.observe(U256::from(11), false), None);
+		assert_eq!(tracker.observe(U256::from(12), true), Some(true));
+	}
+
+	#[test]
+	fn relayed_socket_messages_are_deduplicated() {
+		let mut relayed = RelayedSocketMessages::new(2);
+
+		assert!(!relayed.contains(&(3068, 1, 1)));
+		assert!(relayed.insert((3068, 1, 1)));
+		assert!(relayed.contains(&(3068, 1, 1)));
+		assert!(!relayed.insert((3068, 1, 1)));
+		// the same sequence with another status is a different message
+		assert!(relayed.insert((3068, 1, 5)));
+		// the oldest message is forgotten once the capacity is exceeded
+		assert!(relayed.insert((3068, 2, 1)));
+		assert!(relayed.insert((3068, 1, 1)));
+		assert!(!relayed.insert((3068, 2, 1)));
+	}
+
+	#[test]
+	fn round_stats_are_summarized_when_round_advances() {
+		let mut tracker = RoundStatsTracker::default();
+		let bfc = get_asset_oids()["BFC"];
+		let usdc = get_asset_oids()["USDC"];
+
+		assert_eq!(
+			tracker.record(U256::from(7), bfc, U256::from(100), RelayOutcome::Relayed),
+			None
+		);
+		assert_eq!(
+			tracker.record(U256::from(7), usdc, U256::from(50), RelayOutcome::SequenceEnded),
+			None
+		);
+		// late events of older rounds are counted in the latest round
+		assert_eq!(tracker.record(U256::from(6), bfc, U256::from(10), RelayOutcome::Relayed), None);
+
+		let finished = tracker
+			.record(U256::from(8), bfc, U256::from(1), RelayOutcome::Unselected)
+			.unwrap();
+		assert_eq!(finished.round, U256::from(7));
+		assert_eq!(finished.seen, 3);
+		assert_eq!(finished.relayed, 2);
+		// the amounts of different assets are never summed up
+		assert_eq!(
+			finished.volume,
+			BTreeMap::from([(bfc, U256::from(110)), (usdc, U256::from(50))])
+		);
+		assert_eq!(
+			finished.to_string(),
+			"round(7): seen(3), relayed(2), skipped(sequence_ended: 1), volume(BFC: 110, USDC: 50), selected"
+		);
+
+		// the round transition finishes the round without waiting for the next event
+		let finished = tracker.advance(U256::from(9)).unwrap();
+		assert_eq!(
+			finished.to_string(),
+			"round(8): seen(1), relayed(0), skipped(unselected: 1), volume(BFC: 1), unselected"
+		);
+		assert_eq!(tracker.advance(U256::from(9)), None);
+		assert_eq!(
+			tracker.advance(U256::from(10)).unwrap().to_string(),
+			"round(9): seen(0), relayed(0), skipped(-), volume(-), selection unknown"
+		);
+	}
+
+	#[test]
+	fn relayer_set_kind_into_is_initial() {
+		assert!(RelayerSetKind::Initial.is_initial());
+		assert!(!RelayerSetKind::Current.is_initial());
+	}
+}
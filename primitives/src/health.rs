@@ -0,0 +1,107 @@
+use std::{
+	collections::BTreeMap,
+	sync::Mutex,
+	time::{Duration, Instant},
+};
+
+/// The health state shared among the relayer tasks. Each chain's block polling loop reports its
+/// progress, which determines the liveness of the relayer.
+#[derive(Debug, Default)]
+pub struct HealthState {
+	/// The last time each chain's loop has made progress. <chain_name, instant>
+	progress: Mutex<BTreeMap<String, Instant>>,
+	/// The latest bootstrap progress of each chain. <chain_name, progress>
+	bootstrap_progress: Mutex<BTreeMap<String, String>>,
+	/// The latest selection result of each chain. <chain_name, (round, is_selected)>
+	selection: Mutex<BTreeMap<String, (u64, bool)>>,
+}
+
+impl HealthState {
+	/// Records that the given chain's loop has made progress.
+	pub fn report_progress(&self, chain_name: &str, now: Instant) {
+		self.progress.lock().unwrap().insert(chain_name.to_string(), now);
+	}
+
+	/// Records the bootstrap progress of the given chain. (ex. `4000/10000 blocks, eta 60s`)
+	pub fn report_bootstrap_progress(&self, chain_name: &str, progress: String) {
+		self.bootstrap_progress.lock().unwrap().insert(chain_name.to_string(), progress);
+	}
+
+	/// Returns the latest bootstrap progress of every chain that has reported one.
+	pub fn bootstrap_progress(&self) -> Vec<String> {
+		self.bootstrap_progress
+			.lock()
+			.unwrap()
+			.iter()
+			.map(|(chain_name, progress)| format!("{}({})", chain_name, progress))
+			.collect()
+	}
+
+	/// Records the selection result of the given chain at the given round.
+	pub fn report_selection(&self, chain_name: &str, round: u64, is_selected: bool) {
+		self.selection
+			.lock()
+			.unwrap()
+			.insert(chain_name.to_string(), (round, is_selected));
+	}
+
+	/// Returns the latest selection result of the given chain, if any. (round, is_selected)
+	pub fn selection(&self, chain_name: &str) -> Option<(u64, bool)> {
+		self.selection.lock().unwrap().get(chain_name).copied()
+	}
+
+	/// Returns the chains whose loop hasn't made progress within the given threshold.
+	pub fn stalled_chains(&self, now: Instant, threshold: Duration) -> Vec<String> {
+		self.progress
+			.lock()
+			.unwrap()
+			.iter()
+			.filter(|(_, progressed_at)| now.saturating_duration_since(**progressed_at) > threshold)
+			.map(|(chain_name, _)| chain_name.clone())
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn chains_without_recent_progress_are_stalled() {
+		let health_state = HealthState::default();
+		let started_at = Instant::now();
+		let threshold = Duration::from_secs(60);
+
+		health_state.report_progress("bifrost", started_at);
+		health_state.report_progress("ethereum", started_at);
+		assert!(health_state.stalled_chains(started_at, threshold).is_empty());
+
+		let now = started_at + Duration::from_secs(90);
+		health_state.report_progress("bifrost", now);
+		assert_eq!(health_state.stalled_chains(now, threshold), vec![String::from("ethereum")]);
+	}
+
+	#[test]
+	fn latest_bootstrap_progress_is_reported() {
+		let health_state = HealthState::default();
+		assert!(health_state.bootstrap_progress().is_empty());
+
+		health_state
+			.report_bootstrap_progress("ethereum", String::from("0/100 blocks, eta unknown"));
+		health_state.report_bootstrap_progress("ethereum", String::from("50/100 blocks, eta 10s"));
+		assert_eq!(
+			health_state.bootstrap_progress(),
+			vec![String::from("ethereum(50/100 blocks, eta 10s)")]
+		);
+	}
+
+	#[test]
+	fn latest_selection_is_reported() {
+		let health_state = HealthState::default();
+		assert_eq!(health_state.selection("bifrost"), None);
+
+		health_state.report_selection("bifrost", 7, true);
+		health_state.report_selection("bifrost", 8, false);
+		assert_eq!(health_state.selection("bifrost"), Some((8, false)));
+	}
+}
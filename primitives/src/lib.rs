@@ -1,11 +1,39 @@
 pub mod bootstrap;
+pub mod buffer;
+pub mod bus;
+pub mod checkpoint;
 pub mod cli;
 pub mod constants;
 pub mod contracts;
+pub mod dead_letter;
 pub mod eth;
+pub mod health;
 pub mod periodic;
+pub mod reorg;
+pub mod store;
 pub mod tx;
 
 pub fn sub_display_format(log_target: &str) -> String {
 	format!("{:<019}", log_target)
 }
+
+/// Abbreviates the given address for logs and Sentry messages. ex) `0x1234…abcd`
+pub fn abbreviate_address(address: &ethers::types::Address) -> String {
+	let address = hex::encode(address);
+	format!("0x{}…{}", &address[..4], &address[address.len() - 4..])
+}
+
+#[cfg(test)]
+mod tests {
+	use std::str::FromStr;
+
+	use ethers::types::Address;
+
+	use super::*;
+
+	#[test]
+	fn addresses_are_abbreviated() {
+		let address = Address::from_str("0xd551F33Ca8eCb0Be83d8799D9C68a368BA36Dd52").unwrap();
+		assert_eq!(abbreviate_address(&address), "0xd551…dd52");
+	}
+}
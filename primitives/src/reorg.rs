@@ -0,0 +1,134 @@
+use std::collections::{BTreeMap, VecDeque};
+
+use ethers::types::H256;
+
+/// The hashes of the latest processed blocks of a chain, which are compared against the canonical
+/// chain to detect reorgs of already processed blocks.
+#[derive(Debug)]
+pub struct BlockHashRing {
+	/// The maximum number of tracked blocks.
+	capacity: usize,
+	/// The tracked blocks, from the oldest. <block_number, block_hash>
+	blocks: VecDeque<(u64, H256)>,
+}
+
+impl BlockHashRing {
+	/// Instantiates a new `BlockHashRing` that tracks at most `capacity` blocks.
+	pub fn new(capacity: usize) -> Self {
+		Self { capacity, blocks: VecDeque::with_capacity(capacity) }
+	}
+
+	/// Tracks the hash of the given processed block. Tracked blocks at or after the given block
+	/// are replaced, and the oldest block is dropped once the capacity has been reached.
+	pub fn record(&mut self, block_number: u64, block_hash: H256) {
+		self.truncate_from(block_number);
+		self.blocks.push_back((block_number, block_hash));
+		while self.blocks.len() > self.capacity {
+			self.blocks.pop_front();
+		}
+	}
+
+	/// Drops the tracked blocks at or after the given block.
+	pub fn truncate_from(&mut self, block_number: u64) {
+		while self.blocks.back().is_some_and(|(number, _)| *number >= block_number) {
+			self.blocks.pop_back();
+		}
+	}
+
+	/// Returns the latest tracked block.
+	pub fn latest(&self) -> Option<(u64, H256)> {
+		self.blocks.back().copied()
+	}
+
+	/// Returns the tracked blocks, from the newest.
+	pub fn iter_newest(&self) -> impl Iterator<Item = &(u64, H256)> {
+		self.blocks.iter().rev()
+	}
+}
+
+/// The range of processed blocks that have been replaced by a reorg.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Reorg {
+	/// The first block that may have been replaced, right after the latest common block.
+	pub from_block: u64,
+	/// The latest tracked block, which has been replaced.
+	pub to_block: u64,
+}
+
+impl Reorg {
+	/// Returns the number of blocks that may have been replaced.
+	pub fn depth(&self) -> u64 {
+		(self.to_block + 1).saturating_sub(self.from_block)
+	}
+}
+
+/// Finds the reorged range of the tracked blocks, given the canonical hashes fetched from the
+/// newest tracked block. The search stops at the first block that matches the canonical chain (or
+/// whose canonical hash is unknown). Returns `None` if the latest tracked block is canonical.
+pub fn find_reorg(ring: &BlockHashRing, canonical_hashes: &BTreeMap<u64, H256>) -> Option<Reorg> {
+	let (to_block, _) = ring.latest()?;
+
+	let mut earliest_reorged = None;
+	let mut common = None;
+	for (number, hash) in ring.iter_newest() {
+		match canonical_hashes.get(number) {
+			Some(canonical_hash) if canonical_hash != hash => earliest_reorged = Some(*number),
+			_ => {
+				common = Some(*number);
+				break;
+			},
+		}
+	}
+
+	let earliest_reorged = earliest_reorged?;
+	Some(Reorg {
+		from_block: common.map(|number| number + 1).unwrap_or(earliest_reorged),
+		to_block,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn ring_keeps_latest_blocks() {
+		let mut ring = BlockHashRing::new(3);
+		for number in 1..=5u64 {
+			ring.record(number, H256::from_low_u64_be(number));
+		}
+		assert_eq!(
+			ring.iter_newest().map(|(number, _)| *number).collect::<Vec<_>>(),
+			vec![5, 4, 3]
+		);
+
+		// re-processed blocks replace the tracked ones
+		ring.record(4, H256::repeat_byte(4));
+		assert_eq!(ring.latest(), Some((4, H256::repeat_byte(4))));
+		assert_eq!(ring.iter_newest().count(), 2);
+	}
+
+	#[test]
+	fn deep_reorg_is_detected_down_to_the_common_block() {
+		let mut ring = BlockHashRing::new(16);
+		// batches of 10 blocks, so that the end of each batch is tracked
+		for number in (10..=100u64).step_by(10) {
+			ring.record(number, H256::from_low_u64_be(number));
+		}
+
+		// every block after #70 has been replaced
+		let mut canonical_hashes = BTreeMap::new();
+		for number in [100u64, 90, 80] {
+			canonical_hashes.insert(number, H256::repeat_byte(number as u8));
+		}
+		canonical_hashes.insert(70, H256::from_low_u64_be(70));
+
+		let reorg = find_reorg(&ring, &canonical_hashes).unwrap();
+		assert_eq!(reorg, Reorg { from_block: 71, to_block: 100 });
+		assert_eq!(reorg.depth(), 30);
+
+		// the canonical chain is not reorged
+		canonical_hashes.insert(100, H256::from_low_u64_be(100));
+		assert_eq!(find_reorg(&ring, &canonical_hashes), None);
+	}
+}
@@ -0,0 +1,221 @@
+use std::{
+	collections::BTreeMap,
+	fmt::Debug,
+	fs::{self, OpenOptions},
+	io::{self, Write},
+	path::{Path, PathBuf},
+	sync::Mutex,
+};
+
+/// The extension of temporary files written by `FileStateStore`.
+const TMP_EXTENSION: &str = "tmp";
+
+/// The key-value backend that every persisted relayer state goes through. Keys are plain strings
+/// (ex. `bootstrap-3068.checkpoint`) and values are raw bytes.
+pub trait StateStore: Debug + Send + Sync {
+	/// Returns the value of the given key, if any.
+	fn get(&self, key: &str) -> io::Result<Option<Vec<u8>>>;
+
+	/// Stores the value of the given key. A previous value will be overwritten.
+	fn put(&self, key: &str, value: &[u8]) -> io::Result<()>;
+
+	/// Appends the value to the end of the given key. A missing key is created.
+	fn append(&self, key: &str, value: &[u8]) -> io::Result<()>;
+
+	/// Removes the value of the given key. Removing a missing key is not an error.
+	fn delete(&self, key: &str) -> io::Result<()>;
+
+	/// Returns every entry whose key starts with the given prefix, ordered by key.
+	fn scan(&self, prefix: &str) -> io::Result<Vec<(String, Vec<u8>)>>;
+}
+
+/// The default `StateStore` that stores each entry as a file under a single directory.
+#[derive(Clone, Debug)]
+pub struct FileStateStore {
+	/// The directory where the entries are stored.
+	dir: PathBuf,
+}
+
+impl FileStateStore {
+	/// Instantiates a new `FileStateStore` that stores entries under the given directory.
+	pub fn new(dir: impl AsRef<Path>) -> Self {
+		Self { dir: dir.as_ref().to_path_buf() }
+	}
+}
+
+impl StateStore for FileStateStore {
+	fn get(&self, key: &str) -> io::Result<Option<Vec<u8>>> {
+		match fs::read(self.dir.join(key)) {
+			Ok(value) => Ok(Some(value)),
+			Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+			Err(error) => Err(error),
+		}
+	}
+
+	/// The value is written to a temporary file and renamed, so that a crash never leaves a
+	/// partially written entry.
+	fn put(&self, key: &str, value: &[u8]) -> io::Result<()> {
+		fs::create_dir_all(&self.dir)?;
+
+		let path = self.dir.join(key);
+		let tmp_path = path.with_extension(TMP_EXTENSION);
+		fs::write(&tmp_path, value)?;
+		fs::rename(tmp_path, path)
+	}
+
+	fn append(&self, key: &str, value: &[u8]) -> io::Result<()> {
+		fs::create_dir_all(&self.dir)?;
+
+		let mut file = OpenOptions::new().create(true).append(true).open(self.dir.join(key))?;
+		file.write_all(value)
+	}
+
+	fn delete(&self, key: &str) -> io::Result<()> {
+		match fs::remove_file(self.dir.join(key)) {
+			Err(error) if error.kind() != io::ErrorKind::NotFound => Err(error),
+			_ => Ok(()),
+		}
+	}
+
+	fn scan(&self, prefix: &str) -> io::Result<Vec<(String, Vec<u8>)>> {
+		let entries = match fs::read_dir(&self.dir) {
+			Ok(entries) => entries,
+			Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+			Err(error) => return Err(error),
+		};
+
+		let mut scanned = BTreeMap::new();
+		for entry in entries {
+			let path = entry?.path();
+			if !path.is_file() || path.extension().is_some_and(|ext| ext == TMP_EXTENSION) {
+				continue;
+			}
+			if let Some(key) = path.file_name().and_then(|name| name.to_str()) {
+				if key.starts_with(prefix) {
+					scanned.insert(key.to_string(), fs::read(&path)?);
+				}
+			}
+		}
+		Ok(scanned.into_iter().collect())
+	}
+}
+
+/// The `StateStore` that only keeps entries in memory. Nothing survives a restart.
+#[derive(Debug, Default)]
+pub struct MemoryStateStore {
+	/// The stored entries.
+	entries: Mutex<BTreeMap<String, Vec<u8>>>,
+}
+
+impl StateStore for MemoryStateStore {
+	fn get(&self, key: &str) -> io::Result<Option<Vec<u8>>> {
+		Ok(self.entries.lock().unwrap().get(key).cloned())
+	}
+
+	fn put(&self, key: &str, value: &[u8]) -> io::Result<()> {
+		self.entries.lock().unwrap().insert(key.to_string(), value.to_vec());
+		Ok(())
+	}
+
+	fn append(&self, key: &str, value: &[u8]) -> io::Result<()> {
+		self.entries
+			.lock()
+			.unwrap()
+			.entry(key.to_string())
+			.or_default()
+			.extend_from_slice(value);
+		Ok(())
+	}
+
+	fn delete(&self, key: &str) -> io::Result<()> {
+		self.entries.lock().unwrap().remove(key);
+		Ok(())
+	}
+
+	fn scan(&self, prefix: &str) -> io::Result<Vec<(String, Vec<u8>)>> {
+		Ok(self
+			.entries
+			.lock()
+			.unwrap()
+			.range(prefix.to_string()..)
+			.take_while(|(key, _)| key.starts_with(prefix))
+			.map(|(key, value)| (key.clone(), value.clone()))
+			.collect())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn memory_store_gets_and_overwrites() {
+		let store = MemoryStateStore::default();
+		assert_eq!(store.get("a").unwrap(), None);
+
+		store.put("a", b"1").unwrap();
+		store.put("a", b"2").unwrap();
+		assert_eq!(store.get("a").unwrap(), Some(b"2".to_vec()));
+
+		store.delete("a").unwrap();
+		store.delete("a").unwrap();
+		assert_eq!(store.get("a").unwrap(), None);
+	}
+
+	#[test]
+	fn stores_append_to_the_end() {
+		let dir =
+			std::env::temp_dir().join(format!("bifrost-relayer-append-{}", std::process::id()));
+		let _ = fs::remove_dir_all(&dir);
+
+		let stores: [Box<dyn StateStore>; 2] =
+			[Box::new(MemoryStateStore::default()), Box::new(FileStateStore::new(&dir))];
+		for store in stores {
+			store.append("audit.jsonl", b"1\n").unwrap();
+			store.append("audit.jsonl", b"2\n").unwrap();
+			assert_eq!(store.get("audit.jsonl").unwrap(), Some(b"1\n2\n".to_vec()));
+		}
+
+		fs::remove_dir_all(&dir).unwrap();
+	}
+
+	#[test]
+	fn memory_store_scans_by_prefix() {
+		let store = MemoryStateStore::default();
+		store.put("bootstrap-2", b"2").unwrap();
+		store.put("audit-1", b"a").unwrap();
+		store.put("bootstrap-1", b"1").unwrap();
+		store.put("bootstrap", b"0").unwrap();
+
+		assert_eq!(
+			store.scan("bootstrap-").unwrap(),
+			vec![
+				(String::from("bootstrap-1"), b"1".to_vec()),
+				(String::from("bootstrap-2"), b"2".to_vec()),
+			]
+		);
+		assert_eq!(store.scan("").unwrap().len(), 4);
+		assert!(store.scan("dedupe-").unwrap().is_empty());
+	}
+
+	#[test]
+	fn file_store_scans_by_prefix() {
+		let dir =
+			std::env::temp_dir().join(format!("bifrost-relayer-store-{}", std::process::id()));
+		let _ = fs::remove_dir_all(&dir);
+		let store = FileStateStore::new(&dir);
+		assert!(store.scan("").unwrap().is_empty());
+
+		store.put("bootstrap-1", b"1").unwrap();
+		store.put("audit-1", b"a").unwrap();
+		assert_eq!(store.get("bootstrap-1").unwrap(), Some(b"1".to_vec()));
+		assert_eq!(store.get("bootstrap-2").unwrap(), None);
+		store.delete("bootstrap-2").unwrap();
+		assert_eq!(
+			store.scan("bootstrap-").unwrap(),
+			vec![(String::from("bootstrap-1"), b"1".to_vec())]
+		);
+
+		fs::remove_dir_all(&dir).unwrap();
+	}
+}
@@ -1,6 +1,10 @@
 use std::{
-	collections::BTreeMap,
+	collections::{BTreeMap, VecDeque},
 	fmt::{Display, Formatter},
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc,
+	},
 };
 
 use ethers::types::{
@@ -413,6 +417,8 @@ pub struct TxRequestMessage {
 	/// The flag that represents whether the event is requested by a bootstrap process.
 	/// If true, the event will be processed by a asynchronous task.
 	pub is_bootstrap: bool,
+	/// The guard that keeps the request counted as pending until it has been settled.
+	pub pending_guard: Option<Arc<PendingRequestGuard>>,
 }
 
 impl TxRequestMessage {
@@ -434,9 +440,16 @@ impl TxRequestMessage {
 			give_random_delay,
 			gas_coefficient,
 			is_bootstrap,
+			pending_guard: None,
 		}
 	}
 
+	/// Keeps the request counted as pending by the given guard until it has been settled.
+	pub fn with_pending_guard(mut self, pending_guard: PendingRequestGuard) -> Self {
+		self.pending_guard = Some(Arc::new(pending_guard));
+		self
+	}
+
 	/// Builds a new `EventMessage` to use on transaction retry. This will reduce the remaining
 	/// retry counter and increase the retry interval.
 	pub fn build_retry_event(&mut self) {
@@ -466,3 +479,230 @@ impl TxRequestSender {
 		self.sender.send(message)
 	}
 }
+
+/// Counts the transaction requests that are still pending, which have been requested but not yet
+/// settled (mined, or given up after every retry) by the transaction managers. A request is
+/// settled once every copy of its message has been dropped.
+#[derive(Debug, Default)]
+pub struct PendingRequests {
+	/// The number of pending requests.
+	count: Arc<AtomicU64>,
+}
+
+impl PendingRequests {
+	/// Counts a new pending request until the returned guard has been dropped.
+	pub fn track(&self) -> PendingRequestGuard {
+		self.count.fetch_add(1, Ordering::SeqCst);
+		PendingRequestGuard { count: self.count.clone() }
+	}
+
+	/// Returns `true` if every tracked request has been settled.
+	pub fn is_settled(&self) -> bool {
+		self.count.load(Ordering::SeqCst) == 0
+	}
+}
+
+/// Counts the pending transaction requests per scanned block chunk, so that a chunk boundary can
+/// be checkpointed once every chunk up to it has been settled, without waiting for the requests
+/// of the latest chunks.
+#[derive(Debug, Default)]
+pub struct PendingChunks {
+	/// The requests of the chunk being scanned.
+	current: PendingRequests,
+	/// The scanned chunks that haven't been checkpointed yet, oldest first. <the last block of the
+	/// chunk, the requests of the chunk>
+	scanned: VecDeque<(u64, PendingRequests)>,
+}
+
+impl PendingChunks {
+	/// Counts a new pending request of the chunk being scanned.
+	pub fn track(&self) -> PendingRequestGuard {
+		self.current.track()
+	}
+
+	/// Closes the chunk being scanned at the given last block.
+	pub fn close(&mut self, to_block: u64) {
+		let requests = std::mem::take(&mut self.current);
+		self.scanned.push_back((to_block, requests));
+	}
+
+	/// Returns the latest boundary that every chunk up to has been settled, and forgets the
+	/// chunks up to it.
+	pub fn take_settled_boundary(&mut self) -> Option<u64> {
+		let mut settled_boundary = None;
+		while let Some((to_block, requests)) = self.scanned.front() {
+			if !requests.is_settled() {
+				break;
+			}
+			settled_boundary = Some(*to_block);
+			self.scanned.pop_front();
+		}
+		settled_boundary
+	}
+
+	/// Returns `true` if every tracked request of every chunk has been settled.
+	pub fn is_settled(&self) -> bool {
+		self.current.is_settled() && self.scanned.iter().all(|(_, requests)| requests.is_settled())
+	}
+}
+
+/// The guard of a single pending request. The request is settled once this has been dropped.
+#[derive(Debug)]
+pub struct PendingRequestGuard {
+	/// The number of pending requests.
+	count: Arc<AtomicU64>,
+}
+
+impl Drop for PendingRequestGuard {
+	fn drop(&mut self) {
+		self.count.fetch_sub(1, Ordering::SeqCst);
+	}
+}
+
+/// The classification of a rejected transaction request.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TxRejection {
+	/// The contract rejected the request because another relayer's submission has already
+	/// changed its state. (ex. the round has advanced)
+	Conflict,
+	/// Any other failure of the request.
+	Failure,
+}
+
+impl TxRejection {
+	/// Classifies the given rejection error. The rejection is a conflict if the contract execution
+	/// has been reverted with one of the given reasons. (case-insensitive)
+	pub fn classify(error: &str, conflict_reasons: &[String]) -> Self {
+		let error = error.to_lowercase();
+		if error.contains("revert")
+			&& conflict_reasons.iter().any(|reason| error.contains(&reason.to_lowercase()))
+		{
+			return TxRejection::Conflict;
+		}
+		TxRejection::Failure
+	}
+}
+
+/// The resolution of a sent transaction request that has returned no receipt.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MissingReceipt {
+	/// The nonce of the transaction has been consumed, either by itself or by a replacement.
+	/// Whether the request has been relayed is ambiguous, but it can't be mined anymore.
+	NonceConsumed,
+	/// The transaction is still pending or unknown to the provider.
+	Unresolved,
+}
+
+impl MissingReceipt {
+	/// Resolves the missing receipt from the nonce of the sent transaction (if known to the
+	/// provider) and the number of confirmed transactions of the sender.
+	pub fn resolve(sent_nonce: Option<U256>, confirmed_nonce: U256) -> Self {
+		match sent_nonce {
+			Some(sent_nonce) if sent_nonce < confirmed_nonce => MissingReceipt::NonceConsumed,
+			_ => MissingReceipt::Unresolved,
+		}
+	}
+
+	/// Returns the metric label of the resolution.
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			MissingReceipt::NonceConsumed => "nonce_consumed",
+			MissingReceipt::Unresolved => "unresolved",
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn requests_are_pending_until_every_copy_is_dropped() {
+		let pending_requests = PendingRequests::default();
+		assert!(pending_requests.is_settled());
+
+		let msg = TxRequestMessage::new(
+			TxRequest::Legacy(TransactionRequest::default()),
+			TxRequestMetadata::Heartbeat(HeartbeatMetadata::new(U256::zero(), U256::zero())),
+			false,
+			false,
+			GasCoefficient::Low,
+			true,
+		)
+		.with_pending_guard(pending_requests.track());
+		// the message is copied on retries
+		let retried = msg.clone();
+		drop(msg);
+		assert!(!pending_requests.is_settled());
+
+		drop(retried);
+		assert!(pending_requests.is_settled());
+	}
+
+	#[test]
+	fn chunks_are_settled_in_order() {
+		let mut pending_chunks = PendingChunks::default();
+
+		let first = pending_chunks.track();
+		pending_chunks.close(100);
+		pending_chunks.close(200);
+		let third = pending_chunks.track();
+		pending_chunks.close(300);
+
+		// the later chunks never pass an unsettled chunk
+		assert_eq!(pending_chunks.take_settled_boundary(), None);
+		drop(first);
+		assert_eq!(pending_chunks.take_settled_boundary(), Some(200));
+		assert!(!pending_chunks.is_settled());
+
+		drop(third);
+		assert!(pending_chunks.is_settled());
+		assert_eq!(pending_chunks.take_settled_boundary(), Some(300));
+		assert_eq!(pending_chunks.take_settled_boundary(), None);
+	}
+
+	#[test]
+	fn missing_receipts_are_resolved_by_nonce() {
+		// the sent transaction (or its replacement) has been mined without a receipt.
+		assert_eq!(
+			MissingReceipt::resolve(Some(U256::from(4)), U256::from(5)),
+			MissingReceipt::NonceConsumed
+		);
+		// the sent transaction is still pending or has been dropped.
+		assert_eq!(
+			MissingReceipt::resolve(Some(U256::from(5)), U256::from(5)),
+			MissingReceipt::Unresolved
+		);
+		assert_eq!(MissingReceipt::resolve(None, U256::from(5)), MissingReceipt::Unresolved);
+	}
+
+	#[test]
+	fn reverts_of_competing_submissions_are_conflicts() {
+		let reasons = vec![String::from("already processed"), String::from("invalid round")];
+
+		assert_eq!(
+			TxRejection::classify(
+				"(code: 3, message: execution reverted: Socket: Already Processed, data: None)",
+				&reasons
+			),
+			TxRejection::Conflict
+		);
+		assert_eq!(
+			TxRejection::classify("execution reverted: invalid round", &reasons),
+			TxRejection::Conflict
+		);
+		// reverts with an unknown reason and non-revert errors are genuine failures.
+		assert_eq!(
+			TxRejection::classify("execution reverted: insufficient allowance", &reasons),
+			TxRejection::Failure
+		);
+		assert_eq!(
+			TxRejection::classify("already processed: connection reset", &reasons),
+			TxRejection::Failure
+		);
+		assert_eq!(
+			TxRejection::classify("execution reverted: invalid round", &[]),
+			TxRejection::Failure
+		);
+	}
+}
@@ -0,0 +1,188 @@
+use std::{io, path::Path, sync::Arc, time::SystemTime};
+
+use serde_json::{json, Value};
+use tokio::sync::broadcast::{error::RecvError, Receiver};
+
+use br_primitives::{
+	bus::SystemEvent,
+	store::{FileStateStore, StateStore},
+	sub_display_format,
+};
+
+use crate::cli::LOG_TARGET;
+
+const SUB_LOG_TARGET: &str = "audit";
+
+/// The sink that exports every handler state transition published on the system event bus as a
+/// structured audit record. Each record is appended to a JSON lines file and/or posted to a
+/// webhook.
+pub(super) struct AuditSink {
+	/// The events published on the system event bus.
+	pub receiver: Receiver<SystemEvent>,
+	/// The file that the records are appended to.
+	pub records: Option<AuditRecords>,
+	/// The webhook URL that the records are posted to.
+	pub webhook_url: Option<String>,
+}
+
+impl AuditSink {
+	/// Starts exporting the published events until the bus is closed.
+	pub(super) async fn run(mut self) {
+		let client = reqwest::Client::new();
+		loop {
+			let event = match self.receiver.recv().await {
+				Ok(event) => event,
+				Err(RecvError::Lagged(skipped)) => {
+					log::warn!(
+						target: LOG_TARGET,
+						"-[{}] ⚠️  Skipped {} audit events: the sink has lagged behind",
+						sub_display_format(SUB_LOG_TARGET),
+						skipped,
+					);
+					continue;
+				},
+				Err(RecvError::Closed) => return,
+			};
+			let record = build_record(&event, SystemTime::now());
+
+			if let Some(records) = &self.records {
+				if let Err(error) = records.append(&record) {
+					log::warn!(
+						target: LOG_TARGET,
+						"-[{}] ⚠️  Failed to write an audit record to {}: {}",
+						sub_display_format(SUB_LOG_TARGET),
+						records.key,
+						error,
+					);
+				}
+			}
+			if let Some(webhook_url) = &self.webhook_url {
+				if let Err(error) = client.post(webhook_url).json(&record).send().await {
+					log::warn!(
+						target: LOG_TARGET,
+						"-[{}] ⚠️  Failed to post an audit record to the webhook: {}",
+						sub_display_format(SUB_LOG_TARGET),
+						error,
+					);
+				}
+			}
+		}
+	}
+}
+
+/// Builds the audit record of the given event, which contains the timestamp(unix seconds), the
+/// chain, the transition and its triggering reason.
+pub(super) fn build_record(event: &SystemEvent, recorded_at: SystemTime) -> Value {
+	let (chain_id, transition, reason) = match event {
+		SystemEvent::BootstrapStarted { chain_id, plan } => {
+			(chain_id, "bootstrap_started", plan.clone())
+		},
+		SystemEvent::BootstrapCompleted { chain_id } => (
+			chain_id,
+			"bootstrap_completed",
+			String::from("every bootstrap chunk has been scanned"),
+		),
+		SystemEvent::SelectionChanged { chain_id, round, is_selected } => (
+			chain_id,
+			if *is_selected { "selected" } else { "unselected" },
+			format!(
+				"{} the selected relayer set at round({})",
+				if *is_selected { "entered" } else { "left" },
+				round
+			),
+		),
+		SystemEvent::RoundAdvanced { chain_id, round } => {
+			(chain_id, "round_advanced", format!("round({}) has started", round))
+		},
+		SystemEvent::RelayPaused { chain_id, reason } => (chain_id, "relay_paused", reason.clone()),
+		SystemEvent::RelayResumed { chain_id, reason } => {
+			(chain_id, "relay_resumed", reason.clone())
+		},
+	};
+
+	json!({
+		"timestamp": recorded_at
+			.duration_since(SystemTime::UNIX_EPOCH)
+			.unwrap_or_default()
+			.as_secs(),
+		"chain_id": chain_id,
+		"transition": transition,
+		"reason": reason,
+	})
+}
+
+/// The JSON lines entry of the state store that the audit records are appended to.
+pub(super) struct AuditRecords {
+	/// The state store backend.
+	store: Arc<dyn StateStore>,
+	/// The key of the entry.
+	key: String,
+}
+
+impl AuditRecords {
+	/// Instantiates a new `AuditRecords` that appends to the given file.
+	pub(super) fn new(path: &Path) -> Self {
+		let dir = path.parent().unwrap_or_else(|| Path::new(""));
+		let key = path.file_name().unwrap_or_default().to_string_lossy().into_owned();
+		Self { store: Arc::new(FileStateStore::new(dir)), key }
+	}
+
+	/// Appends the given record to the entry as a single JSON line.
+	fn append(&self, record: &Value) -> io::Result<()> {
+		self.store.append(&self.key, format!("{}\n", record).as_bytes())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::Duration;
+
+	use br_primitives::store::MemoryStateStore;
+	use ethers::types::U256;
+
+	use super::*;
+
+	#[test]
+	fn records_contain_timestamp_and_reason() {
+		let record = build_record(
+			&SystemEvent::SelectionChanged {
+				chain_id: 3068,
+				round: U256::from(7),
+				is_selected: true,
+			},
+			SystemTime::UNIX_EPOCH + Duration::from_secs(100),
+		);
+		assert_eq!(
+			record,
+			json!({
+				"timestamp": 100,
+				"chain_id": 3068,
+				"transition": "selected",
+				"reason": "entered the selected relayer set at round(7)",
+			})
+		);
+	}
+
+	#[test]
+	fn records_are_appended_as_lines() {
+		let store = Arc::new(MemoryStateStore::default());
+		let records = AuditRecords { store: store.clone(), key: String::from("audit.jsonl") };
+
+		for event in [
+			SystemEvent::BootstrapStarted { chain_id: 1, plan: String::from("chunks(1)") },
+			SystemEvent::BootstrapCompleted { chain_id: 1 },
+		] {
+			records.append(&build_record(&event, SystemTime::UNIX_EPOCH)).unwrap();
+		}
+
+		let lines: Vec<Value> = String::from_utf8(store.get("audit.jsonl").unwrap().unwrap())
+			.unwrap()
+			.lines()
+			.map(|line| serde_json::from_str(line).unwrap())
+			.collect();
+		assert_eq!(lines.len(), 2);
+		assert_eq!(lines[0]["transition"], "bootstrap_started");
+		assert_eq!(lines[0]["reason"], "chunks(1)");
+		assert_eq!(lines[1]["transition"], "bootstrap_completed");
+	}
+}
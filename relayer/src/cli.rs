@@ -1,6 +1,10 @@
-use br_primitives::sub_display_format;
+use br_primitives::{
+	constants::cli::{DEFAULT_HEALTH_PORT, DEFAULT_STATUS_REFRESH_INTERVAL_SEC},
+	eth::ChainID,
+	sub_display_format,
+};
 use chrono::{Datelike, Local};
-use clap::{CommandFactory, FromArgMatches, Parser};
+use clap::{Args, CommandFactory, FromArgMatches, Parser};
 
 #[derive(Debug, Parser)]
 pub struct Cli {
@@ -10,6 +14,118 @@ pub struct Cli {
 	/// with the chainspec.
 	#[arg(long, value_name = "CHAIN_SPEC", default_value = "mainnet")]
 	pub chain: String,
+
+	/// Replay the socket events of a single block in dry-run and exit.
+	///
+	/// The decision made on each event will be printed, but nothing will be submitted.
+	#[arg(long, value_name = "CHAIN_ID:BLOCK", value_parser = parse_replay_target)]
+	pub replay_block: Option<ReplayTarget>,
+
+	/// Replay the socket events of a block range in dry-run and exit.
+	///
+	/// The decision made on each event will be printed, but nothing will be submitted.
+	#[arg(
+		long,
+		value_name = "CHAIN_ID:FROM..TO",
+		value_parser = parse_replay_range,
+		conflicts_with = "replay_block"
+	)]
+	pub replay_range: Option<ReplayRange>,
+
+	/// Replay the range in reverse chronological order, so that the newest events come first.
+	#[arg(long, requires = "replay_range")]
+	pub reverse: bool,
+
+	/// Print the bootstrap plan of each chain and exit.
+	///
+	/// The plan shows the block window and the number of chunks to scan, without fetching any
+	/// event.
+	#[arg(long, conflicts_with_all = ["replay_block", "replay_range"])]
+	pub bootstrap_plan: bool,
+
+	#[command(subcommand)]
+	pub subcommand: Option<Subcommand>,
+}
+
+/// The subcommands of the relayer.
+#[derive(Debug, clap::Subcommand)]
+pub enum Subcommand {
+	/// Print a compact live view of a running relayer.
+	///
+	/// The view is fetched from the `/status` snapshot of the relayer's health endpoint, which
+	/// must be enabled. Nothing is modified, so this is safe to run at any time.
+	Status(StatusCmd),
+}
+
+/// The `status` subcommand.
+#[derive(Debug, Clone, Args)]
+pub struct StatusCmd {
+	/// The host of the running relayer's health endpoint.
+	#[arg(long, default_value = "127.0.0.1")]
+	pub host: String,
+
+	/// The port of the running relayer's health endpoint.
+	#[arg(long, default_value_t = DEFAULT_HEALTH_PORT)]
+	pub port: u16,
+
+	/// The refresh interval in seconds. The view is printed once if set to zero.
+	#[arg(long, default_value_t = DEFAULT_STATUS_REFRESH_INTERVAL_SEC)]
+	pub interval: u64,
+}
+
+/// The target block of a dry-run replay.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayTarget {
+	/// The chain ID of the target block.
+	pub chain_id: ChainID,
+	/// The target block number.
+	pub block_number: u64,
+}
+
+/// Parses a replay target formatted as `CHAIN_ID:BLOCK`.
+fn parse_replay_target(value: &str) -> Result<ReplayTarget, String> {
+	let (chain_id, block_number) = value
+		.split_once(':')
+		.ok_or(format!("expected CHAIN_ID:BLOCK, got `{}`", value))?;
+
+	Ok(ReplayTarget {
+		chain_id: chain_id.parse().map_err(|_| format!("invalid chain ID `{}`", chain_id))?,
+		block_number: block_number
+			.parse()
+			.map_err(|_| format!("invalid block number `{}`", block_number))?,
+	})
+}
+
+/// The target block range of a dry-run replay.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayRange {
+	/// The chain ID of the target blocks.
+	pub chain_id: ChainID,
+	/// The first block of the range.
+	pub from_block: u64,
+	/// The last block of the range.
+	pub to_block: u64,
+}
+
+/// Parses a replay range formatted as `CHAIN_ID:FROM..TO`.
+fn parse_replay_range(value: &str) -> Result<ReplayRange, String> {
+	let (chain_id, range) = value
+		.split_once(':')
+		.ok_or(format!("expected CHAIN_ID:FROM..TO, got `{}`", value))?;
+	let (from_block, to_block) =
+		range.split_once("..").ok_or(format!("expected FROM..TO, got `{}`", range))?;
+
+	let range = ReplayRange {
+		chain_id: chain_id.parse().map_err(|_| format!("invalid chain ID `{}`", chain_id))?,
+		from_block: from_block
+			.parse()
+			.map_err(|_| format!("invalid block number `{}`", from_block))?,
+		to_block: to_block.parse().map_err(|_| format!("invalid block number `{}`", to_block))?,
+	};
+	if range.from_block > range.to_block {
+		return Err(format!("empty range `{}`", value));
+	}
+	Ok(range)
 }
 
 impl Cli {
@@ -154,3 +270,53 @@ pub const SUB_LOG_TARGET: &str = "main";
 
 const TESTNET_CONFIG_FILE_PATH: &str = "configs/config.testnet.yaml";
 const MAINNET_CONFIG_FILE_PATH: &str = "configs/config.mainnet.yaml";
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parses_replay_target() {
+		assert_eq!(
+			parse_replay_target("3068:1234"),
+			Ok(ReplayTarget { chain_id: 3068, block_number: 1234 })
+		);
+		assert!(parse_replay_target("3068").is_err());
+		assert!(parse_replay_target("bifrost:1234").is_err());
+		assert!(parse_replay_target("3068:latest").is_err());
+	}
+
+	#[test]
+	fn parses_replay_range() {
+		assert_eq!(
+			parse_replay_range("3068:100..200"),
+			Ok(ReplayRange { chain_id: 3068, from_block: 100, to_block: 200 })
+		);
+		assert!(parse_replay_range("3068:100").is_err());
+		assert!(parse_replay_range("3068:200..100").is_err());
+		assert!(parse_replay_range("3068:100..latest").is_err());
+	}
+
+	#[test]
+	fn reverse_requires_replay_range() {
+		assert!(
+			Cli::try_parse_from(["relayer", "--replay-range", "3068:1..2", "--reverse"])
+				.unwrap()
+				.reverse
+		);
+		assert!(Cli::try_parse_from(["relayer", "--reverse"]).is_err());
+	}
+
+	#[test]
+	fn parses_status_subcommand() {
+		let cli = Cli::try_parse_from(["relayer", "status", "--port", "9001"]).unwrap();
+		match cli.subcommand {
+			Some(Subcommand::Status(cmd)) => {
+				assert_eq!(cmd.host, "127.0.0.1");
+				assert_eq!(cmd.port, 9001);
+				assert_eq!(cmd.interval, DEFAULT_STATUS_REFRESH_INTERVAL_SEC);
+			},
+			None => panic!("expected the status subcommand"),
+		}
+	}
+}
@@ -0,0 +1,217 @@
+use std::{collections::BTreeMap, net::SocketAddr, sync::Arc, time::Instant};
+
+use ethers::providers::Http;
+use serde_json::{json, Value};
+use tokio::{
+	io::{AsyncReadExt, AsyncWriteExt},
+	net::{TcpListener, TcpStream},
+	sync::RwLock,
+	time::Duration,
+};
+
+use br_client::eth::EthClient;
+use br_primitives::{
+	eth::{BootstrapState, ChainID},
+	health::HealthState,
+	sub_display_format,
+};
+
+use crate::{cli::LOG_TARGET, status::StatusRecorder};
+
+const SUB_LOG_TARGET: &str = "health";
+
+/// The HTTP endpoint that exposes the liveness and readiness of the relayer to orchestrators.
+///
+/// - `/health/live`: whether every chain's block polling loop has recently made progress.
+/// - `/health/ready`: whether the bootstrap has been completed and every chain is reachable. While
+///   bootstrapping, the progress and estimated remaining time of each chain are returned.
+/// - `/status`: the JSON snapshot of the relayer state, read by the `status` subcommand.
+pub(super) struct HealthEndpoint {
+	/// The health state reported by each chain's block polling loop.
+	pub health_state: Arc<HealthState>,
+	/// The current bootstrap state of each chain.
+	pub bootstrap_states: Arc<RwLock<BTreeMap<ChainID, BootstrapState>>>,
+	/// The clients of every chain.
+	pub clients: Vec<Arc<EthClient<Http>>>,
+	/// A loop is considered stalled if it hasn't made progress within this threshold.
+	pub stall_threshold: Duration,
+	/// The recorder of the published system events.
+	pub status_recorder: Arc<StatusRecorder>,
+}
+
+impl HealthEndpoint {
+	/// Verifies whether every chain's block polling loop has recently made progress.
+	fn liveness(&self) -> Result<(), String> {
+		let stalled_chains = self.health_state.stalled_chains(Instant::now(), self.stall_threshold);
+		if !stalled_chains.is_empty() {
+			return Err(format!("stalled chains: {}", stalled_chains.join(", ")));
+		}
+		Ok(())
+	}
+
+	/// Verifies whether the bootstrap has been completed and every chain is reachable.
+	async fn readiness(&self) -> Result<(), String> {
+		let bootstrap_states = self.bootstrap_states.read().await;
+		if !bootstrap_states.values().all(|state| *state == BootstrapState::NormalStart) {
+			let progress = self.health_state.bootstrap_progress();
+			if progress.is_empty() {
+				return Err(format!("bootstrap in progress: {:?}", *bootstrap_states));
+			}
+			return Err(format!(
+				"bootstrap in progress: {:?}, {}",
+				*bootstrap_states,
+				progress.join(", ")
+			));
+		}
+
+		let unreachable_chains: Vec<String> = self
+			.clients
+			.iter()
+			.filter(|client| !client.is_reachable())
+			.map(|client| client.get_chain_name())
+			.collect();
+		if !unreachable_chains.is_empty() {
+			return Err(format!("unreachable chains: {}", unreachable_chains.join(", ")));
+		}
+		Ok(())
+	}
+
+	/// Builds the JSON snapshot of the bootstrap state, the state of each chain, and the recent
+	/// counts and errors of the published system events.
+	async fn status(&self) -> Value {
+		let is_ready = self.readiness().await.is_ok();
+		let bootstrap_states = self.bootstrap_states.read().await;
+		let stalled_chains = self.health_state.stalled_chains(Instant::now(), self.stall_threshold);
+
+		let chains: Vec<Value> = self
+			.clients
+			.iter()
+			.map(|client| {
+				let chain_name = client.get_chain_name();
+				let selection = self.health_state.selection(&chain_name);
+				json!({
+					"name": chain_name,
+					"is_reachable": client.is_reachable(),
+					"is_stalled": stalled_chains.contains(&chain_name),
+					"round": selection.map(|(round, _)| round),
+					"is_selected": selection.map(|(_, is_selected)| is_selected),
+				})
+			})
+			.collect();
+
+		json!({
+			"is_ready": is_ready,
+			"bootstrap_states": bootstrap_states
+				.iter()
+				.map(|(chain_id, state)| (chain_id.to_string(), format!("{:?}", state)))
+				.collect::<BTreeMap<String, String>>(),
+			"bootstrap_progress": self.health_state.bootstrap_progress(),
+			"chains": chains,
+			"counts": self.status_recorder.counts(),
+			"recent_errors": self.status_recorder.recent_errors(),
+		})
+	}
+
+	/// Handles a single request.
+	async fn handle(&self, mut stream: TcpStream) {
+		let mut buffer = [0u8; 1024];
+		let size = stream.read(&mut buffer).await.unwrap_or_default();
+		let request = String::from_utf8_lossy(&buffer[..size]);
+
+		let response = match request_path(&request) {
+			"/health/live" => build_response(Some(self.liveness())),
+			"/health/ready" => build_response(Some(self.readiness().await)),
+			"/status" => build_json_response(&self.status().await),
+			_ => build_response(None),
+		};
+		// responses are best-effort, the orchestrator will retry its probe.
+		let _ = stream.write_all(response.as_bytes()).await;
+	}
+
+	/// Starts accepting requests on the given address.
+	pub(super) async fn run(self, addr: SocketAddr) {
+		let listener = match TcpListener::bind(addr).await {
+			Ok(listener) => listener,
+			Err(error) => {
+				log::error!(
+					target: LOG_TARGET,
+					"-[{}] ❗️ Failed to bind the health endpoint on {}: {}",
+					sub_display_format(SUB_LOG_TARGET),
+					addr,
+					error,
+				);
+				return;
+			},
+		};
+
+		let endpoint = Arc::new(self);
+		loop {
+			if let Ok((stream, _)) = listener.accept().await {
+				let endpoint = endpoint.clone();
+				tokio::spawn(async move { endpoint.handle(stream).await });
+			}
+		}
+	}
+}
+
+/// Returns the path of the given HTTP request. ex) `GET /health/live HTTP/1.1`
+fn request_path(request: &str) -> &str {
+	request.split_whitespace().nth(1).unwrap_or_default()
+}
+
+/// Builds the HTTP response of a health check. `None` if the requested path is unknown.
+fn build_response(result: Option<Result<(), String>>) -> String {
+	let (status, body) = match result {
+		Some(Ok(())) => ("200 OK", String::from("ok")),
+		Some(Err(reason)) => ("503 Service Unavailable", reason),
+		None => ("404 Not Found", String::from("not found")),
+	};
+	format_response(status, "text/plain", &body)
+}
+
+/// Builds the HTTP response of the given JSON body.
+fn build_json_response(body: &Value) -> String {
+	format_response("200 OK", "application/json", &body.to_string())
+}
+
+/// Formats an HTTP response with the given status, content type and body.
+fn format_response(status: &str, content_type: &str, body: &str) -> String {
+	format!(
+		"HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+		status,
+		content_type,
+		body.len(),
+		body
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn requests_are_routed_by_path() {
+		assert_eq!(
+			request_path("GET /health/ready HTTP/1.1\r\nHost: localhost\r\n\r\n"),
+			"/health/ready"
+		);
+		assert_eq!(request_path(""), "");
+	}
+
+	#[test]
+	fn unhealthy_checks_are_unavailable() {
+		assert!(build_response(Some(Ok(()))).starts_with("HTTP/1.1 200 OK\r\n"));
+		assert!(build_response(Some(Err(String::from("stalled chains: bifrost"))))
+			.starts_with("HTTP/1.1 503 Service Unavailable\r\n"));
+		assert!(build_response(Some(Err(String::from("stalled chains: bifrost"))))
+			.ends_with("\r\n\r\nstalled chains: bifrost"));
+		assert!(build_response(None).starts_with("HTTP/1.1 404 Not Found\r\n"));
+	}
+
+	#[test]
+	fn status_is_served_as_json() {
+		let response = build_json_response(&json!({ "is_ready": false }));
+		assert!(response.starts_with("HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n"));
+		assert!(response.ends_with("\r\n\r\n{\"is_ready\":false}"));
+	}
+}
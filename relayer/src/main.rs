@@ -1,5 +1,8 @@
+mod audit;
 mod cli;
+mod health;
 mod service;
+mod status;
 mod verification;
 
 use std::io::Write;
@@ -12,7 +15,7 @@ use chrono::Local;
 use env_logger::fmt::Color;
 use log::Level;
 
-use crate::cli::Cli;
+use crate::cli::{Cli, Subcommand};
 
 fn main() {
 	env_logger::Builder::new()
@@ -40,12 +43,33 @@ fn main() {
 	let cli = Cli::from_args();
 
 	let tokio_runtime = build_runtime().unwrap();
+	if let Some(Subcommand::Status(cmd)) = &cli.subcommand {
+		tokio_runtime.block_on(status::watch(cmd));
+		return;
+	}
 	let configuration =
 		create_configuration(tokio_runtime.handle().clone(), cli.load_spec()).unwrap();
 
 	sc_sysinfo::print_sysinfo(&sc_sysinfo::gather_sysinfo());
 	cli.print_relayer_infos();
 
+	if cli.bootstrap_plan {
+		tokio_runtime.block_on(service::print_bootstrap_plan(configuration)).unwrap();
+		return;
+	}
+	if let Some(target) = cli.replay_block {
+		tokio_runtime
+			.block_on(service::replay_block(configuration, target.chain_id, target.block_number))
+			.unwrap();
+		return;
+	}
+	if let Some(range) = cli.replay_range {
+		tokio_runtime
+			.block_on(service::replay_range(configuration, range, cli.reverse))
+			.unwrap();
+		return;
+	}
+
 	let runner = Runner::new(configuration, tokio_runtime).unwrap();
 	runner
 		.run_relayer_until_exit(|config| async move {
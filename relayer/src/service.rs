@@ -1,16 +1,22 @@
 use std::{
 	collections::BTreeMap,
 	net::{Ipv4Addr, SocketAddr},
+	path::Path,
+	str::FromStr,
 	sync::Arc,
 	time::Duration,
 };
 
-use ethers::providers::{Http, Provider};
+use ethers::{
+	providers::{Http, Provider},
+	types::{Address, U64},
+};
 use futures::FutureExt;
 use sc_service::{config::PrometheusConfig, Error as ServiceError, TaskManager};
 
 use br_client::eth::{
-	events::EventManager,
+	endpoint::{HealthThresholds, RpcEndpoints},
+	events::{EventManager, EventReceiver},
 	handlers::{RoundupRelayHandler, SocketRelayHandler},
 	traits::{Handler, TransactionManager},
 	tx::{Eip1559TransactionManager, LegacyTransactionManager},
@@ -25,17 +31,32 @@ use br_primitives::{
 	bootstrap::BootstrapSharedData,
 	cli::{Configuration, HandlerType},
 	constants::{
-		cli::{DEFAULT_GET_LOGS_BATCH_SIZE, DEFAULT_MIN_PRIORITY_FEE, DEFAULT_PROMETHEUS_PORT},
-		errors::{INVALID_CHAIN_ID, INVALID_PRIVATE_KEY, INVALID_PROVIDER_URL},
+		cli::{
+			DEFAULT_ENDPOINT_ERROR_THRESHOLD, DEFAULT_ENDPOINT_LATENCY_THRESHOLD_MS,
+			DEFAULT_ENDPOINT_RECOVERY_INTERVAL_MS, DEFAULT_GET_LOGS_BATCH_SIZE,
+			DEFAULT_HEALTH_PORT, DEFAULT_HEALTH_STALL_THRESHOLD_SEC, DEFAULT_MAX_BUFFERED_EVENTS,
+			DEFAULT_MAX_FORWARD_GAP, DEFAULT_MIN_PRIORITY_FEE, DEFAULT_PROMETHEUS_PORT,
+			DEFAULT_REPORT_MAX_FILES, DEFAULT_SIGNING_STAGGER_INTERVAL_MS,
+		},
+		errors::{
+			INVALID_CHAIN_ID, INVALID_PRIVATE_KEY, INVALID_PROVIDER_URL,
+			INVALID_TRUSTED_RELAYER_ADDRESS,
+		},
+	},
+	eth::{
+		AggregatorContracts, BootstrapState, ChainID, EventFilter, ProtocolContracts,
+		ProviderMetadata,
 	},
-	eth::{AggregatorContracts, BootstrapState, ChainID, ProtocolContracts, ProviderMetadata},
 	periodic::RollbackSender,
 	sub_display_format,
 	tx::TxRequestSender,
 };
 
 use crate::{
-	cli::{LOG_TARGET, SUB_LOG_TARGET},
+	audit::{AuditRecords, AuditSink},
+	cli::{ReplayRange, LOG_TARGET, SUB_LOG_TARGET},
+	health::HealthEndpoint,
+	status::StatusRecorder,
 	verification::assert_configuration_validity,
 };
 
@@ -44,13 +65,97 @@ pub fn relay(config: Configuration) -> Result<TaskManager, ServiceError> {
 	new_relay_base(config).map(|RelayBase { task_manager, .. }| task_manager)
 }
 
+/// Replays the socket events of a single block of the given chain in dry-run. The relayer
+/// components are only constructed, no tasks will be spawned and nothing will be submitted.
+pub async fn replay_block(
+	config: Configuration,
+	chain_id: ChainID,
+	block_number: u64,
+) -> Result<(), ServiceError> {
+	let DryRunBase { handler_deps, .. } = new_dry_run_base(&config)?;
+
+	let handler = handler_deps
+		.socket_relay_handlers
+		.iter()
+		.find(|handler| handler.client.get_chain_id() == chain_id)
+		.expect(INVALID_CHAIN_ID);
+
+	handler.replay_block(U64::from(block_number)).await;
+	Ok(())
+}
+
+/// Replays the socket events of a block range of the given chain in dry-run. The relayer
+/// components are only constructed, no tasks will be spawned and nothing will be submitted.
+pub async fn replay_range(
+	config: Configuration,
+	range: ReplayRange,
+	is_reverse: bool,
+) -> Result<(), ServiceError> {
+	let DryRunBase { handler_deps, .. } = new_dry_run_base(&config)?;
+
+	let handler = handler_deps
+		.socket_relay_handlers
+		.iter()
+		.find(|handler| handler.client.get_chain_id() == range.chain_id)
+		.expect(INVALID_CHAIN_ID);
+
+	handler
+		.replay_range(U64::from(range.from_block), U64::from(range.to_block), is_reverse)
+		.await;
+	Ok(())
+}
+
+/// Prints the bootstrap plan of each chain. The relayer components are only constructed, no
+/// tasks will be spawned and no event will be fetched.
+pub async fn print_bootstrap_plan(config: Configuration) -> Result<(), ServiceError> {
+	let DryRunBase { handler_deps, .. } = new_dry_run_base(&config)?;
+
+	let mut total_chunks = 0;
+	for handler in &handler_deps.socket_relay_handlers {
+		match handler.get_bootstrap_plan().await {
+			Ok(Some(plan)) => {
+				total_chunks += plan.chunks().len();
+				log::info!(
+					target: LOG_TARGET,
+					"-[{}] 📋 Bootstrap plan of {}: {}",
+					sub_display_format(SUB_LOG_TARGET),
+					handler.client.get_chain_name(),
+					plan,
+				);
+			},
+			Ok(None) => log::info!(
+				target: LOG_TARGET,
+				"-[{}] 📋 Bootstrap is not configured",
+				sub_display_format(SUB_LOG_TARGET),
+			),
+			Err(error) => log::warn!(
+				target: LOG_TARGET,
+				"-[{}] ⚠️  Failed to plan the bootstrap of {}: {}",
+				sub_display_format(SUB_LOG_TARGET),
+				handler.client.get_chain_name(),
+				error,
+			),
+		}
+	}
+	log::info!(
+		target: LOG_TARGET,
+		"-[{}] 📋 Bootstrap plan total: chains({}), chunks({})",
+		sub_display_format(SUB_LOG_TARGET),
+		handler_deps.socket_relay_handlers.len(),
+		total_chunks,
+	);
+	Ok(())
+}
+
 /// Initializes periodic components.
 fn construct_periodics(
+	config: &Configuration,
 	bootstrap_shared_data: BootstrapSharedData,
 	relayer_deps: &ManagerDeps,
 ) -> PeriodicDeps {
 	let clients = &relayer_deps.clients;
 	let tx_request_senders = &relayer_deps.tx_request_senders;
+	let system = &config.relayer_config.system;
 
 	let mut rollback_emitters = vec![];
 	let mut rollback_senders = BTreeMap::new();
@@ -70,8 +175,12 @@ fn construct_periodics(
 
 	// initialize socket rollback handlers
 	tx_request_senders.iter().for_each(|tx_request_sender| {
-		let (rollback_emitter, rollback_sender) =
-			SocketRollbackEmitter::new(tx_request_sender.clone(), clients.clone());
+		let (rollback_emitter, rollback_sender) = SocketRollbackEmitter::new(
+			tx_request_sender.clone(),
+			clients.clone(),
+			system.max_buffered_events.unwrap_or(DEFAULT_MAX_BUFFERED_EVENTS),
+			system.shedding_policy.unwrap_or_default(),
+		);
 		rollback_emitters.push(rollback_emitter);
 		rollback_senders.insert(
 			tx_request_sender.id,
@@ -98,6 +207,7 @@ fn construct_handlers(
 	let mut handlers = (vec![], vec![]);
 	let PeriodicDeps { rollback_senders, .. } = periodic_deps;
 	let ManagerDeps { clients, event_managers, tx_request_senders, .. } = manager_deps;
+	let system = &config.relayer_config.system;
 
 	config.relayer_config.handler_configs.iter().for_each(|handler_config| {
 		match handler_config.handler_type {
@@ -106,20 +216,30 @@ fn construct_handlers(
 					*target,
 					tx_request_senders.clone(),
 					rollback_senders.clone(),
-					event_managers.get(target).expect(INVALID_CHAIN_ID).sender.subscribe(),
+					vec![EventReceiver::new(
+						*target,
+						event_managers.get(target).expect(INVALID_CHAIN_ID).sender.subscribe(),
+					)],
 					clients.clone(),
+					handler_config,
+					system.max_buffered_events.unwrap_or(DEFAULT_MAX_BUFFERED_EVENTS),
+					system.shedding_policy.unwrap_or_default(),
 					Arc::new(bootstrap_shared_data.clone()),
 				));
 			}),
 			HandlerType::Roundup => {
 				handlers.1.push(RoundupRelayHandler::new(
 					tx_request_senders.clone(),
-					event_managers
-						.get(&handler_config.watch_list[0])
-						.expect(INVALID_CHAIN_ID)
-						.sender
-						.subscribe(),
+					vec![EventReceiver::new(
+						handler_config.watch_list[0],
+						event_managers
+							.get(&handler_config.watch_list[0])
+							.expect(INVALID_CHAIN_ID)
+							.sender
+							.subscribe(),
+					)],
 					clients.clone(),
+					handler_config,
 					Arc::new(bootstrap_shared_data.clone()),
 				));
 			},
@@ -134,9 +254,15 @@ fn construct_managers(
 	bootstrap_shared_data: BootstrapSharedData,
 	task_manager: &TaskManager,
 ) -> ManagerDeps {
-	let prometheus_config = &config.relayer_config.prometheus_config;
 	let evm_providers = &config.relayer_config.evm_providers;
 	let system = &config.relayer_config.system;
+	let is_observer = system.observer_mode.unwrap_or(false);
+	let trusted_signers: Option<Vec<Address>> = system.trusted_relayers.as_ref().map(|relayers| {
+		relayers
+			.iter()
+			.map(|relayer| Address::from_str(relayer).expect(INVALID_TRUSTED_RELAYER_ADDRESS))
+			.collect()
+	});
 
 	let mut clients = vec![];
 	let mut tx_managers = (vec![], vec![]);
@@ -146,14 +272,54 @@ fn construct_managers(
 	// iterate each evm provider and construct inner components.
 	evm_providers.iter().for_each(|evm_provider| {
 		let is_native = evm_provider.is_native.unwrap_or(false);
-		let provider = Provider::<Http>::try_from(evm_provider.provider.clone())
-			.expect(INVALID_PROVIDER_URL)
-			.interval(Duration::from_millis(evm_provider.call_interval));
-
+		let build_provider = |url: &String| {
+			Provider::<Http>::try_from(url.clone())
+				.expect(INVALID_PROVIDER_URL)
+				.interval(Duration::from_millis(evm_provider.call_interval))
+		};
+		let provider = build_provider(&evm_provider.provider);
+		let fallback_providers = evm_provider
+			.fallback_providers
+			.iter()
+			.flatten()
+			.map(|url| Arc::new(build_provider(url)))
+			.collect();
+
+		let wallet = match &system.private_key {
+			Some(private_key) if !is_observer => {
+				WalletManager::from_private_key(private_key.as_str(), evm_provider.id)
+					.expect(INVALID_PRIVATE_KEY)
+			},
+			_ => WalletManager::observer(evm_provider.id),
+		};
+		let wallet = match &system.signing_priority {
+			Some(priority) => wallet.with_signing_priority(
+				priority.index,
+				priority.stagger_interval.unwrap_or(DEFAULT_SIGNING_STAGGER_INTERVAL_MS),
+			),
+			None => wallet,
+		};
 		let client = Arc::new(EthClient::new(
-			WalletManager::from_private_key(system.private_key.as_str(), evm_provider.id)
-				.expect(INVALID_PRIVATE_KEY),
-			Arc::new(provider.clone()),
+			wallet,
+			RpcEndpoints::new(
+				Arc::new(provider.clone()),
+				fallback_providers,
+				HealthThresholds {
+					latency: Duration::from_millis(
+						evm_provider
+							.endpoint_latency_threshold
+							.unwrap_or(DEFAULT_ENDPOINT_LATENCY_THRESHOLD_MS),
+					),
+					consecutive_errors: evm_provider
+						.endpoint_error_threshold
+						.unwrap_or(DEFAULT_ENDPOINT_ERROR_THRESHOLD),
+					recovery_interval: Duration::from_millis(
+						evm_provider
+							.endpoint_recovery_interval
+							.unwrap_or(DEFAULT_ENDPOINT_RECOVERY_INTERVAL_MS),
+					),
+				},
+			),
 			ProviderMetadata::new(
 				evm_provider.name.clone(),
 				evm_provider.id,
@@ -161,7 +327,18 @@ fn construct_managers(
 				evm_provider.call_interval,
 				evm_provider.get_logs_batch_size.unwrap_or(DEFAULT_GET_LOGS_BATCH_SIZE),
 				is_native,
-			),
+			)
+			.with_event_bounds(evm_provider.deployment_block, evm_provider.max_event_age)
+			.with_max_forward_gap(evm_provider.max_forward_gap.unwrap_or(DEFAULT_MAX_FORWARD_GAP))
+			.with_deep_reorg_threshold(evm_provider.deep_reorg_threshold)
+			.with_conflict_revert_reasons(evm_provider.conflict_revert_reasons.clone())
+			.with_requery_missing_receipt(evm_provider.requery_missing_receipt.unwrap_or(false))
+			.with_trusted_signers(trusted_signers.clone())
+			.with_event_filter(EventFilter::new(
+				evm_provider.strict_event_filtering.unwrap_or(false),
+				evm_provider.allowed_contracts.clone().unwrap_or_default(),
+				evm_provider.allowed_events.clone().unwrap_or_default(),
+			)),
 			ProtocolContracts::new(
 				Arc::new(provider.clone()),
 				evm_provider.socket_address.clone(),
@@ -185,9 +362,13 @@ fn construct_managers(
 					client.clone(),
 					evm_provider.min_priority_fee.unwrap_or(DEFAULT_MIN_PRIORITY_FEE).into(),
 					evm_provider.duplicate_confirm_delay,
+					evm_provider.max_random_delay,
 					task_manager.spawn_handle(),
 				);
-				tx_managers.1.push(tx_manager);
+				// an observer never submits transactions, so the manager is dropped right away
+				if !is_observer {
+					tx_managers.1.push(tx_manager);
+				}
 				tx_request_senders.push(Arc::new(TxRequestSender::new(
 					evm_provider.id,
 					sender,
@@ -200,9 +381,12 @@ fn construct_managers(
 					evm_provider.min_gas_price,
 					evm_provider.is_initially_escalated.unwrap_or(false),
 					evm_provider.duplicate_confirm_delay,
+					evm_provider.max_random_delay,
 					task_manager.spawn_handle(),
 				);
-				tx_managers.0.push(tx_manager);
+				if !is_observer {
+					tx_managers.0.push(tx_manager);
+				}
 				tx_request_senders.push(Arc::new(TxRequestSender::new(
 					evm_provider.id,
 					sender,
@@ -213,10 +397,7 @@ fn construct_managers(
 		let event_manager = EventManager::new(
 			client.clone(),
 			Arc::new(bootstrap_shared_data.clone()),
-			match &prometheus_config {
-				Some(config) => config.is_enabled,
-				None => false,
-			},
+			is_metric_enabled(config),
 		);
 
 		clients.push(client);
@@ -236,8 +417,9 @@ fn spawn_relayer_tasks(
 
 	let FullDeps { bootstrap_shared_data, manager_deps, periodic_deps, handler_deps } = deps;
 
-	let BootstrapSharedData { socket_barrier, bootstrap_states, .. } = bootstrap_shared_data;
-	let ManagerDeps { tx_managers, event_managers, .. } = manager_deps;
+	let BootstrapSharedData { socket_barrier, bootstrap_states, health_state, event_bus, .. } =
+		bootstrap_shared_data;
+	let ManagerDeps { clients, tx_managers, event_managers, .. } = manager_deps;
 	let PeriodicDeps {
 		mut heartbeat_sender,
 		mut oracle_price_feeder,
@@ -270,20 +452,23 @@ fn spawn_relayer_tasks(
 		)
 	});
 
-	// spawn heartbeat sender
-	task_manager
-		.spawn_essential_handle()
-		.spawn("heartbeat", Some("heartbeat"), async move { heartbeat_sender.run().await });
+	// an observer never sends heartbeats or price feeds
+	if !config.relayer_config.system.observer_mode.unwrap_or(false) {
+		// spawn heartbeat sender
+		task_manager
+			.spawn_essential_handle()
+			.spawn("heartbeat", Some("heartbeat"), async move { heartbeat_sender.run().await });
 
-	// spawn oracle price feeder
-	task_manager.spawn_essential_handle().spawn(
-		Box::leak(
-			format!("{}-oracle-price-feeder", oracle_price_feeder.client.get_chain_name())
-				.into_boxed_str(),
-		),
-		Some("oracle"),
-		async move { oracle_price_feeder.run().await },
-	);
+		// spawn oracle price feeder
+		task_manager.spawn_essential_handle().spawn(
+			Box::leak(
+				format!("{}-oracle-price-feeder", oracle_price_feeder.client.get_chain_name())
+					.into_boxed_str(),
+			),
+			Some("oracle"),
+			async move { oracle_price_feeder.run().await },
+		);
+	}
 	// spawn socket rollback emitters
 	rollback_emitters.into_iter().for_each(|mut emitter| {
 		task_manager.spawn_essential_handle().spawn(
@@ -307,20 +492,20 @@ fn spawn_relayer_tasks(
 					.into_boxed_str(),
 			),
 			Some("handlers"),
-			async move {
+			br_metrics::bind_sentry_hub(async move {
 				socket_barrier_clone.wait().await;
 
 				// After All of barrier complete the waiting
 				let mut guard = is_bootstrapped.write().await;
-				if guard.iter().all(|s| *s == BootstrapState::BootstrapRoundUpPhase2) {
-					for state in guard.iter_mut() {
+				if guard.values().all(|s| *s == BootstrapState::BootstrapRoundUpPhase2) {
+					for state in guard.values_mut() {
 						*state = BootstrapState::BootstrapSocketRelay;
 					}
 				}
 				drop(guard);
 
 				handler.run().await
-			},
+			}),
 		);
 	});
 
@@ -332,7 +517,7 @@ fn spawn_relayer_tasks(
 					.into_boxed_str(),
 			),
 			Some("handlers"),
-			async move { handler.run().await },
+			br_metrics::bind_sentry_hub(async move { handler.run().await }),
 		);
 	});
 
@@ -358,6 +543,89 @@ fn spawn_relayer_tasks(
 		)
 	});
 
+	// setup statsd exporter
+	if let Some(statsd_config) = &config.relayer_config.statsd_config {
+		if statsd_config.is_enabled {
+			#[cfg(feature = "statsd")]
+			{
+				use br_primitives::constants::{
+					cli::{DEFAULT_STATSD_HOST, DEFAULT_STATSD_PORT},
+					errors::INVALID_STATSD_ENDPOINT,
+				};
+
+				br_metrics::statsd::setup(
+					statsd_config.host.as_deref().unwrap_or(DEFAULT_STATSD_HOST),
+					statsd_config.port.unwrap_or(DEFAULT_STATSD_PORT),
+					statsd_config.prefix.clone(),
+				)
+				.expect(INVALID_STATSD_ENDPOINT);
+			}
+
+			#[cfg(not(feature = "statsd"))]
+			log::warn!(
+				target: LOG_TARGET,
+				"-[{}] ⚠️  Statsd exporter is enabled but the relayer was built without the `statsd` feature",
+				sub_display_format(SUB_LOG_TARGET),
+			);
+		}
+	}
+
+	// setup summary report
+	if let Some(report_config) = &config.relayer_config.report_config {
+		br_metrics::report::setup(
+			&report_config.path,
+			Duration::from_secs(report_config.interval.saturating_mul(60)),
+			report_config.max_files.unwrap_or(DEFAULT_REPORT_MAX_FILES),
+		);
+	}
+
+	// spawn audit sink
+	if let Some(audit_config) = &config.relayer_config.audit_config {
+		if audit_config.is_enabled {
+			let audit_sink = AuditSink {
+				receiver: event_bus.subscribe(),
+				records: audit_config.path.as_ref().map(|path| AuditRecords::new(Path::new(path))),
+				webhook_url: audit_config.webhook_url.clone(),
+			};
+			task_manager.spawn_handle().spawn("audit-sink", None, audit_sink.run());
+		}
+	}
+
+	// spawn health endpoint
+	if let Some(health_config) = &config.relayer_config.health_config {
+		if health_config.is_enabled {
+			let interface = match health_config.is_external.unwrap_or(false) {
+				true => Ipv4Addr::UNSPECIFIED,
+				false => Ipv4Addr::LOCALHOST,
+			};
+
+			let status_recorder = Arc::new(StatusRecorder::default());
+			let receiver = event_bus.subscribe();
+			let recorder = status_recorder.clone();
+			task_manager
+				.spawn_handle()
+				.spawn("status-recorder", None, async move { recorder.run(receiver).await });
+
+			let health_endpoint = HealthEndpoint {
+				health_state,
+				bootstrap_states: bootstrap_states.clone(),
+				clients,
+				stall_threshold: Duration::from_secs(
+					health_config.stall_threshold.unwrap_or(DEFAULT_HEALTH_STALL_THRESHOLD_SEC),
+				),
+				status_recorder,
+			};
+			task_manager.spawn_handle().spawn(
+				"health-endpoint",
+				None,
+				health_endpoint.run(SocketAddr::new(
+					interface.into(),
+					health_config.port.unwrap_or(DEFAULT_HEALTH_PORT),
+				)),
+			);
+		}
+	}
+
 	// spawn prometheus endpoint
 	if let Some(prometheus_config) = prometheus_config {
 		if prometheus_config.is_enabled {
@@ -388,16 +656,38 @@ fn spawn_relayer_tasks(
 	task_manager
 }
 
+/// Verifies whether any metric exporter is enabled. Self balance synchronization will only be
+/// done when metrics are exported.
+fn is_metric_enabled(config: &Configuration) -> bool {
+	let is_prometheus_enabled = match &config.relayer_config.prometheus_config {
+		Some(config) => config.is_enabled,
+		None => false,
+	};
+	let is_statsd_enabled = match &config.relayer_config.statsd_config {
+		Some(config) => cfg!(feature = "statsd") && config.is_enabled,
+		None => false,
+	};
+	is_prometheus_enabled || is_statsd_enabled
+}
+
 /// Log the configured relay targets.
 fn print_relay_targets(manager_deps: &ManagerDeps) {
 	let tx_managers = &manager_deps.tx_managers;
 
-	log::info!(
-		target: LOG_TARGET,
-		"-[{}] 👤 Relayer: {:?}",
-		sub_display_format(SUB_LOG_TARGET),
-		&manager_deps.clients[0].address()
-	);
+	if manager_deps.clients[0].wallet.is_observer() {
+		log::info!(
+			target: LOG_TARGET,
+			"-[{}] 👀 Observer mode: relayer selection and transaction submission are disabled",
+			sub_display_format(SUB_LOG_TARGET),
+		);
+	} else {
+		log::info!(
+			target: LOG_TARGET,
+			"-[{}] 👤 Relayer: {:?}",
+			sub_display_format(SUB_LOG_TARGET),
+			&manager_deps.clients[0].address()
+		);
+	}
 
 	if !tx_managers.0.is_empty() {
 		log::info!(
@@ -425,6 +715,22 @@ fn print_relay_targets(manager_deps: &ManagerDeps) {
 	}
 }
 
+/// Builds the internal components for dry-run operations without spawning any task.
+fn new_dry_run_base(config: &Configuration) -> Result<DryRunBase, ServiceError> {
+	assert_configuration_validity(config);
+
+	let task_manager = TaskManager::new(config.clone().tokio_handle, None)?;
+
+	let bootstrap_shared_data = BootstrapSharedData::new(config);
+
+	let manager_deps = construct_managers(config, bootstrap_shared_data.clone(), &task_manager);
+	let periodic_deps = construct_periodics(config, bootstrap_shared_data.clone(), &manager_deps);
+	let handler_deps =
+		construct_handlers(config, &periodic_deps, &manager_deps, bootstrap_shared_data);
+
+	Ok(DryRunBase { _task_manager: task_manager, handler_deps })
+}
+
 /// Builds the internal components for the relayer service and spawns asynchronous tasks.
 fn new_relay_base(config: Configuration) -> Result<RelayBase, ServiceError> {
 	assert_configuration_validity(&config);
@@ -434,11 +740,12 @@ fn new_relay_base(config: Configuration) -> Result<RelayBase, ServiceError> {
 	let bootstrap_shared_data = BootstrapSharedData::new(&config);
 
 	let manager_deps = construct_managers(&config, bootstrap_shared_data.clone(), &task_manager);
-	let periodic_deps = construct_periodics(bootstrap_shared_data.clone(), &manager_deps);
+	let periodic_deps = construct_periodics(&config, bootstrap_shared_data.clone(), &manager_deps);
 	let handler_deps =
 		construct_handlers(&config, &periodic_deps, &manager_deps, bootstrap_shared_data.clone());
 
 	print_relay_targets(&manager_deps);
+	br_metrics::set_sentry_relayer_address(&format!("{:?}", manager_deps.clients[0].address()));
 
 	Ok(RelayBase {
 		task_manager: spawn_relayer_tasks(
@@ -454,6 +761,13 @@ struct RelayBase {
 	task_manager: TaskManager,
 }
 
+struct DryRunBase {
+	/// The task manager that owns the constructed components.
+	_task_manager: TaskManager,
+	/// The handlers used for dry-run operations.
+	handler_deps: HandlerDeps,
+}
+
 struct ManagerDeps {
 	/// The `EthClient`'s for each specified chain.
 	clients: Vec<Arc<EthClient<Http>>>,
@@ -0,0 +1,227 @@
+use std::{
+	collections::{BTreeMap, VecDeque},
+	sync::Mutex,
+	time::{Duration, SystemTime},
+};
+
+use serde_json::{json, Value};
+use tokio::{
+	sync::broadcast::{error::RecvError, Receiver},
+	time::sleep,
+};
+
+use br_primitives::{
+	bus::SystemEvent, constants::config::STATUS_RECENT_ERRORS_CAPACITY, sub_display_format,
+};
+
+use crate::{
+	audit::build_record,
+	cli::{StatusCmd, LOG_TARGET},
+};
+
+const SUB_LOG_TARGET: &str = "status";
+
+/// The system events recorded for the status snapshot.
+#[derive(Debug, Default)]
+struct RecordedEvents {
+	/// The number of each published transition. <transition, count>
+	counts: BTreeMap<String, u64>,
+	/// The latest transitions that interrupted relaying, oldest first.
+	recent_errors: VecDeque<Value>,
+}
+
+/// The recorder of the system events that are summarized in the status snapshot.
+#[derive(Debug, Default)]
+pub(super) struct StatusRecorder {
+	/// The events recorded so far.
+	events: Mutex<RecordedEvents>,
+}
+
+impl StatusRecorder {
+	/// Records the given event.
+	fn record(&self, event: &SystemEvent, recorded_at: SystemTime) {
+		let record = build_record(event, recorded_at);
+		let mut events = self.events.lock().unwrap();
+
+		let transition = record["transition"].as_str().unwrap_or_default().to_string();
+		*events.counts.entry(transition).or_default() += 1;
+
+		if matches!(event, SystemEvent::RelayPaused { .. }) {
+			if events.recent_errors.len() == STATUS_RECENT_ERRORS_CAPACITY {
+				events.recent_errors.pop_front();
+			}
+			events.recent_errors.push_back(record);
+		}
+	}
+
+	/// Returns the number of each published transition.
+	pub(super) fn counts(&self) -> Value {
+		json!(self.events.lock().unwrap().counts)
+	}
+
+	/// Returns the latest transitions that interrupted relaying, oldest first.
+	pub(super) fn recent_errors(&self) -> Value {
+		json!(self.events.lock().unwrap().recent_errors)
+	}
+
+	/// Starts recording the published events until the bus is closed.
+	pub(super) async fn run(&self, mut receiver: Receiver<SystemEvent>) {
+		loop {
+			match receiver.recv().await {
+				Ok(event) => self.record(&event, SystemTime::now()),
+				Err(RecvError::Lagged(_)) => continue,
+				Err(RecvError::Closed) => return,
+			}
+		}
+	}
+}
+
+/// Renders the compact view of the given status snapshot.
+fn render_status(snapshot: &Value) -> String {
+	let mut view = format!(
+		"Bifrost relayer status ({})\nbootstrap: {}\n",
+		if snapshot["is_ready"].as_bool().unwrap_or_default() { "ready" } else { "not ready" },
+		snapshot["bootstrap_states"]
+			.as_object()
+			.into_iter()
+			.flatten()
+			.map(|(chain_id, state)| format!(
+				"{}({})",
+				chain_id,
+				state.as_str().unwrap_or_default()
+			))
+			.collect::<Vec<String>>()
+			.join(", "),
+	);
+	for progress in snapshot["bootstrap_progress"].as_array().into_iter().flatten() {
+		view.push_str(&format!("  {}\n", progress.as_str().unwrap_or_default()));
+	}
+
+	view.push_str("chains:\n");
+	for chain in snapshot["chains"].as_array().into_iter().flatten() {
+		let selection = match (chain["round"].as_u64(), chain["is_selected"].as_bool()) {
+			(Some(round), Some(is_selected)) => {
+				format!("round({}) {}", round, if is_selected { "selected" } else { "unselected" })
+			},
+			_ => String::from("-"),
+		};
+		view.push_str(&format!(
+			"  {:<24} {:<12} {:<8} {}\n",
+			chain["name"].as_str().unwrap_or_default(),
+			if chain["is_reachable"].as_bool().unwrap_or_default() {
+				"reachable"
+			} else {
+				"unreachable"
+			},
+			if chain["is_stalled"].as_bool().unwrap_or_default() { "stalled" } else { "live" },
+			selection,
+		));
+	}
+
+	let counts: Vec<String> = snapshot["counts"]
+		.as_object()
+		.into_iter()
+		.flatten()
+		.map(|(transition, count)| format!("{}({})", transition, count))
+		.collect();
+	view.push_str(&format!(
+		"counts: {}\n",
+		if counts.is_empty() { String::from("-") } else { counts.join(", ") }
+	));
+
+	view.push_str("recent errors:\n");
+	for error in snapshot["recent_errors"].as_array().into_iter().flatten() {
+		view.push_str(&format!(
+			"  [{}] chain({}) {}\n",
+			error["timestamp"],
+			error["chain_id"],
+			error["reason"].as_str().unwrap_or_default(),
+		));
+	}
+	view
+}
+
+/// Prints the live view of the running relayer's status snapshot, refreshed on every interval.
+/// The snapshot is only read, so this is safe to run at any time.
+pub(super) async fn watch(cmd: &StatusCmd) {
+	let url = format!("http://{}:{}/status", cmd.host, cmd.port);
+	let client = reqwest::Client::new();
+
+	loop {
+		let snapshot = match client.get(&url).send().await {
+			Ok(response) => response.json::<Value>().await.map_err(|error| error.to_string()),
+			Err(error) => Err(error.to_string()),
+		};
+		match snapshot {
+			// clear the screen and move the cursor to the top-left corner.
+			Ok(snapshot) => print!("\x1b[2J\x1b[H{}", render_status(&snapshot)),
+			Err(error) => log::warn!(
+				target: LOG_TARGET,
+				"-[{}] ⚠️  Failed to fetch the status snapshot from {}: {}",
+				sub_display_format(SUB_LOG_TARGET),
+				url,
+				error,
+			),
+		}
+
+		if cmd.interval == 0 {
+			return;
+		}
+		sleep(Duration::from_secs(cmd.interval)).await;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn recent_errors_are_capped() {
+		let recorder = StatusRecorder::default();
+		recorder.record(&SystemEvent::BootstrapCompleted { chain_id: 1 }, SystemTime::UNIX_EPOCH);
+		for _ in 0..STATUS_RECENT_ERRORS_CAPACITY + 2 {
+			recorder.record(
+				&SystemEvent::RelayPaused { chain_id: 1, reason: String::from("unselected") },
+				SystemTime::UNIX_EPOCH,
+			);
+		}
+
+		assert_eq!(
+			recorder.counts(),
+			json!({
+				"bootstrap_completed": 1,
+				"relay_paused": STATUS_RECENT_ERRORS_CAPACITY + 2,
+			})
+		);
+		assert_eq!(
+			recorder.recent_errors().as_array().unwrap().len(),
+			STATUS_RECENT_ERRORS_CAPACITY
+		);
+	}
+
+	#[test]
+	fn status_is_rendered_compactly() {
+		let view = render_status(&json!({
+			"is_ready": true,
+			"bootstrap_states": { "1": "NormalStart", "3068": "BootstrapSocketRelay" },
+			"bootstrap_progress": [],
+			"chains": [{
+				"name": "bifrost",
+				"is_reachable": true,
+				"is_stalled": false,
+				"round": 7,
+				"is_selected": true,
+			}],
+			"counts": { "selected": 1 },
+			"recent_errors": [{ "timestamp": 100, "chain_id": 3068, "reason": "unselected" }],
+		}));
+
+		assert!(view.starts_with(
+			"Bifrost relayer status (ready)\nbootstrap: 1(NormalStart), 3068(BootstrapSocketRelay)\n"
+		));
+		assert!(view.contains("bifrost"));
+		assert!(view.contains("round(7) selected"));
+		assert!(view.contains("counts: selected(1)\n"));
+		assert!(view.ends_with("recent errors:\n  [100] chain(3068) unselected\n"));
+	}
+}
@@ -1,21 +1,41 @@
+use std::{collections::BTreeSet, str::FromStr};
+
 use br_primitives::{
-	cli::Configuration,
+	cli::{Configuration, EVMProvider},
 	constants::{
 		cli::{
 			DEFAULT_BOOTSTRAP_ROUND_OFFSET, DEFAULT_DUPLICATE_CONFIRM_DELAY_MS,
-			DEFAULT_ESCALATE_PERCENTAGE, DEFAULT_GET_LOGS_BATCH_SIZE, MAX_BLOCK_CONFIRMATIONS,
+			DEFAULT_ENDPOINT_ERROR_THRESHOLD, DEFAULT_ENDPOINT_LATENCY_THRESHOLD_MS,
+			DEFAULT_ENDPOINT_RECOVERY_INTERVAL_MS, DEFAULT_ESCALATE_PERCENTAGE,
+			DEFAULT_GET_LOGS_BATCH_SIZE, DEFAULT_MAX_RANDOM_DELAY_MS, MAX_BLOCK_CONFIRMATIONS,
 			MAX_BOOTSTRAP_ROUND_OFFSET, MAX_CALL_INTERVAL_MS, MAX_DUPLICATE_CONFIRM_DELAY_MS,
-			MAX_ESCALATE_PERCENTAGE, MIN_GET_LOGS_BATCH_SIZE,
+			MAX_ENDPOINT_ERROR_THRESHOLD, MAX_ENDPOINT_LATENCY_THRESHOLD_MS,
+			MAX_ENDPOINT_RECOVERY_INTERVAL_MS, MAX_ESCALATE_PERCENTAGE, MAX_RANDOM_DELAY_MS,
+			MIN_ENDPOINT_ERROR_THRESHOLD, MIN_ENDPOINT_LATENCY_THRESHOLD_MS,
+			MIN_ENDPOINT_RECOVERY_INTERVAL_MS, MIN_GET_LOGS_BATCH_SIZE,
+		},
+		errors::{
+			DUPLICATE_CHAIN_ID, DUPLICATE_PROVIDER_URL, MISSING_NATIVE_AUTHORITY_CONTRACT,
+			MISSING_PRIVATE_KEY, PARAMETER_OUT_OF_RANGE,
 		},
-		errors::PARAMETER_OUT_OF_RANGE,
 	},
 };
+use ethers::types::Address;
 
 /// Verifies whether the certain numeric parameters specified in the configuration YAML file are valid.
 /// If any single paramater has been provided, the system will panic on-start.
 pub(super) fn assert_configuration_validity(config: &Configuration) {
 	let bootstrap_config = &config.relayer_config.bootstrap_config;
 	let evm_providers = &config.relayer_config.evm_providers;
+	let system = &config.relayer_config.system;
+
+	// assert `system`
+	assert!(
+		system.observer_mode.unwrap_or(false) || system.private_key.is_some(),
+		"{} [parameter: {}]",
+		MISSING_PRIVATE_KEY,
+		"system.private_key"
+	);
 
 	// assert `bootstrap_config`
 	if let Some(bootstrap_config) = bootstrap_config {
@@ -32,6 +52,8 @@ pub(super) fn assert_configuration_validity(config: &Configuration) {
 	}
 
 	// assert `evm_providers`
+	assert_distinct_evm_providers(evm_providers);
+	assert_native_authority_contract(evm_providers);
 	evm_providers.iter().for_each(|evm_provider| {
 		assert!(
 			(0..=MAX_CALL_INTERVAL_MS).contains(&evm_provider.call_interval),
@@ -67,6 +89,16 @@ pub(super) fn assert_configuration_validity(config: &Configuration) {
 				DEFAULT_DUPLICATE_CONFIRM_DELAY_MS
 			);
 		}
+		if let Some(max_random_delay) = evm_provider.max_random_delay {
+			assert!(
+				(0..=MAX_RANDOM_DELAY_MS).contains(&max_random_delay),
+				"{} [parameter: {}, range: 0…{}, default: {}]",
+				PARAMETER_OUT_OF_RANGE,
+				"evm_provider.max_random_delay",
+				MAX_RANDOM_DELAY_MS,
+				DEFAULT_MAX_RANDOM_DELAY_MS
+			);
+		}
 		if let Some(get_logs_batch_size) = evm_provider.get_logs_batch_size {
 			let max_get_logs_batch_size =
 				MAX_CALL_INTERVAL_MS.saturating_div(evm_provider.call_interval);
@@ -80,5 +112,193 @@ pub(super) fn assert_configuration_validity(config: &Configuration) {
 				DEFAULT_GET_LOGS_BATCH_SIZE
 			);
 		}
+		assert_endpoint_health_thresholds(evm_provider);
+	});
+}
+
+/// Verifies whether the RPC endpoint health thresholds are within their ranges. An out of range
+/// threshold would degrade the endpoints on every request, or never.
+fn assert_endpoint_health_thresholds(evm_provider: &EVMProvider) {
+	if let Some(endpoint_latency_threshold) = evm_provider.endpoint_latency_threshold {
+		assert!(
+			(MIN_ENDPOINT_LATENCY_THRESHOLD_MS..=MAX_ENDPOINT_LATENCY_THRESHOLD_MS)
+				.contains(&endpoint_latency_threshold),
+			"{} [parameter: {}, range: {}…{}, default: {}]",
+			PARAMETER_OUT_OF_RANGE,
+			"evm_provider.endpoint_latency_threshold",
+			MIN_ENDPOINT_LATENCY_THRESHOLD_MS,
+			MAX_ENDPOINT_LATENCY_THRESHOLD_MS,
+			DEFAULT_ENDPOINT_LATENCY_THRESHOLD_MS
+		);
+	}
+	if let Some(endpoint_error_threshold) = evm_provider.endpoint_error_threshold {
+		assert!(
+			(MIN_ENDPOINT_ERROR_THRESHOLD..=MAX_ENDPOINT_ERROR_THRESHOLD)
+				.contains(&endpoint_error_threshold),
+			"{} [parameter: {}, range: {}…{}, default: {}]",
+			PARAMETER_OUT_OF_RANGE,
+			"evm_provider.endpoint_error_threshold",
+			MIN_ENDPOINT_ERROR_THRESHOLD,
+			MAX_ENDPOINT_ERROR_THRESHOLD,
+			DEFAULT_ENDPOINT_ERROR_THRESHOLD
+		);
+	}
+	if let Some(endpoint_recovery_interval) = evm_provider.endpoint_recovery_interval {
+		assert!(
+			(MIN_ENDPOINT_RECOVERY_INTERVAL_MS..=MAX_ENDPOINT_RECOVERY_INTERVAL_MS)
+				.contains(&endpoint_recovery_interval),
+			"{} [parameter: {}, range: {}…{}, default: {}]",
+			PARAMETER_OUT_OF_RANGE,
+			"evm_provider.endpoint_recovery_interval",
+			MIN_ENDPOINT_RECOVERY_INTERVAL_MS,
+			MAX_ENDPOINT_RECOVERY_INTERVAL_MS,
+			DEFAULT_ENDPOINT_RECOVERY_INTERVAL_MS
+		);
+	}
+}
+
+/// Verifies whether every configured chain has a distinct chain ID and distinct endpoints. Chains
+/// are mapped by their IDs, so a duplicated ID would silently drop one of the chains.
+fn assert_distinct_evm_providers(evm_providers: &[EVMProvider]) {
+	let mut chain_ids = BTreeSet::new();
+	let mut provider_urls = BTreeSet::new();
+
+	evm_providers.iter().for_each(|evm_provider| {
+		assert!(
+			chain_ids.insert(evm_provider.id),
+			"{} [parameter: {}, chain: {}, id: {}]",
+			DUPLICATE_CHAIN_ID,
+			"evm_provider.id",
+			evm_provider.name,
+			evm_provider.id
+		);
+
+		std::iter::once(&evm_provider.provider)
+			.chain(evm_provider.fallback_providers.iter().flatten())
+			.for_each(|url| {
+				// URLs may contain API keys, so only the chain will be displayed.
+				assert!(
+					provider_urls.insert(url.trim_end_matches('/').to_lowercase()),
+					"{} [parameter: {}, chain: {}]",
+					DUPLICATE_PROVIDER_URL,
+					"evm_provider.provider",
+					evm_provider.name
+				);
+			});
 	});
 }
+
+/// Verifies whether the native chain has the authority contract configured. Rounds are always
+/// fetched from the native chain's authority contract (e.g. on bootstrap), so a missing contract
+/// would only fail deep in the first call.
+fn assert_native_authority_contract(evm_providers: &[EVMProvider]) {
+	evm_providers
+		.iter()
+		.filter(|evm_provider| evm_provider.is_native.unwrap_or(false))
+		.for_each(|evm_provider| {
+			assert!(
+				Address::from_str(&evm_provider.authority_address)
+					.is_ok_and(|address| !address.is_zero()),
+				"{} [parameter: {}, chain: {}]",
+				MISSING_NATIVE_AUTHORITY_CONTRACT,
+				"evm_provider.authority_address",
+				evm_provider.name
+			);
+		});
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn evm_provider(name: &str, id: u32, provider: &str) -> EVMProvider {
+		serde_yaml::from_str(&format!(
+			"name: {}\n\
+			id: {}\n\
+			provider: {}\n\
+			call_interval: 3000\n\
+			block_confirmations: 5\n\
+			is_relay_target: true\n\
+			socket_address: \"0x0000000000000000000000000000000000000000\"\n\
+			authority_address: \"0x0000000000000000000000000000000000000000\"\n",
+			name, id, provider
+		))
+		.unwrap()
+	}
+
+	#[test]
+	fn distinct_evm_providers() {
+		assert_distinct_evm_providers(&[
+			evm_provider("bifrost", 3068, "https://bifrost.example"),
+			evm_provider("ethereum", 1, "https://ethereum.example"),
+		]);
+	}
+
+	#[test]
+	#[should_panic(expected = "Duplicate chain ID provided")]
+	fn colliding_chain_ids() {
+		assert_distinct_evm_providers(&[
+			evm_provider("ethereum", 1, "https://ethereum.example"),
+			evm_provider("arbitrum", 1, "https://arbitrum.example"),
+		]);
+	}
+
+	#[test]
+	#[should_panic(expected = "Duplicate provider URL provided")]
+	fn colliding_provider_urls() {
+		assert_distinct_evm_providers(&[
+			evm_provider("ethereum", 1, "https://rpc.example"),
+			evm_provider("arbitrum", 42161, "https://rpc.example/"),
+		]);
+	}
+
+	#[test]
+	fn native_authority_contract_is_configured() {
+		assert_native_authority_contract(&[
+			EVMProvider {
+				is_native: Some(true),
+				authority_address: String::from("0x0000000000000000000000000000000000000400"),
+				..evm_provider("bifrost", 3068, "https://bifrost.example")
+			},
+			// external chains aren't checked
+			evm_provider("ethereum", 1, "https://ethereum.example"),
+		]);
+	}
+
+	#[test]
+	#[should_panic(expected = "The native chain requires an authority contract")]
+	fn native_without_authority_contract() {
+		assert_native_authority_contract(&[EVMProvider {
+			is_native: Some(true),
+			..evm_provider("bifrost", 3068, "https://bifrost.example")
+		}]);
+	}
+
+	#[test]
+	fn endpoint_health_thresholds_in_range() {
+		assert_endpoint_health_thresholds(&EVMProvider {
+			endpoint_latency_threshold: Some(MIN_ENDPOINT_LATENCY_THRESHOLD_MS),
+			endpoint_error_threshold: Some(MAX_ENDPOINT_ERROR_THRESHOLD),
+			endpoint_recovery_interval: Some(DEFAULT_ENDPOINT_RECOVERY_INTERVAL_MS),
+			..evm_provider("bifrost", 3068, "https://bifrost.example")
+		});
+	}
+
+	#[test]
+	#[should_panic(expected = "evm_provider.endpoint_error_threshold")]
+	fn zero_endpoint_error_threshold() {
+		assert_endpoint_health_thresholds(&EVMProvider {
+			endpoint_error_threshold: Some(0),
+			..evm_provider("bifrost", 3068, "https://bifrost.example")
+		});
+	}
+
+	#[test]
+	#[should_panic(expected = "evm_provider.endpoint_recovery_interval")]
+	fn endpoint_recovery_interval_out_of_range() {
+		assert_endpoint_health_thresholds(&EVMProvider {
+			endpoint_recovery_interval: Some(MAX_ENDPOINT_RECOVERY_INTERVAL_MS + 1),
+			..evm_provider("bifrost", 3068, "https://bifrost.example")
+		});
+	}
+}